@@ -1,10 +1,59 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use std::collections::BTreeMap as Map;
 
 use crate::user::{User, UserMetadata};
 use crate::Body;
 
-#[derive(Deserialize, Debug)]
+#[cfg(test)]
+mod test {
+    use crate::annotation::{AnnotationRequest, Referent};
+
+    #[test]
+    fn annotation_request_test() {
+        let request = AnnotationRequest::new(
+            "hello **world!**",
+            "http://seejohncode.com/2014/01/27/vim-commands-piping/",
+            "execute commands",
+        )
+        .before_html("You may know that you can ")
+        .after_html(" from inside of vim, with a vim command:")
+        .title("Secret of Mana");
+        assert_eq!(
+            serde_json::to_string(&request).unwrap(),
+            r#"{"annotation":{"body":{"markdown":"hello **world!**"}},"referent":{"raw_annotatable_url":"http://seejohncode.com/2014/01/27/vim-commands-piping/","fragment":"execute commands","context_for_display":{"before_html":"You may know that you can ","after_html":" from inside of vim, with a vim command:"}},"web_page":{"canonical_url":null,"og_url":null,"title":"Secret of Mana"}}"#
+        );
+    }
+
+    #[test]
+    fn referent_round_trip_test() {
+        let user = r#"{
+            "api_path": "/users/1", "avatar": {"tiny": {"url": "", "bounding_box": {"width": 16, "height": 16}}},
+            "header_image_url": "", "id": 1, "login": "genius", "name": "Genius", "url": "https://genius.com/genius",
+            "current_user_metadata": {"permissions": [], "excluded_permissions": ["follow"], "interactions": {"vote": "up"}}
+        }"#;
+        let referent: Referent = serde_json::from_str(&format!(
+            r#"{{
+                "_type": "referent", "annotator_id": 1, "annotator_login": "genius", "api_path": "/referents/1",
+                "classification": "accepted", "fragment": "execute commands", "id": 1, "is_description": false,
+                "path": "/1/execute-commands", "range": {{"content": "execute commands", "startOffset": 10}},
+                "song_id": null, "url": "https://genius.com/1",
+                "annotations": [{{
+                    "api_path": "/annotations/1", "body": {{"plain": "hello world!"}}, "comment_count": 0,
+                    "community": true, "has_voters": true, "id": 1, "pinned": false, "share_url": "",
+                    "state": "accepted", "url": "", "verified": false, "votes_total": 3,
+                    "current_user_metadata": {{"permissions": [], "excluded_permissions": []}},
+                    "authors": [{{"attribution": 0.33333334, "user": {user}}}]
+                }}]
+            }}"#
+        ))
+        .unwrap();
+        let json = serde_json::to_string(&referent).unwrap();
+        assert_eq!(serde_json::from_str::<Referent>(&json).unwrap(), referent);
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct Referent {
     pub _type: String,
     pub annotator_id: u32,
@@ -19,20 +68,46 @@ pub struct Referent {
     /// > Only with `user-core` level token
     pub is_image: Option<bool>,
     pub path: String,
-    pub range: Map<String, String>,
+    /// Where the fragment is, `content` is the annotated text and referents of web pages also have the position of the fragment in the page.
+    pub range: Map<String, Value>,
     pub song_id: Option<u32>,
     pub url: String,
     /// > Only with `user-core` level token
     pub current_user_metadata: Option<UserMetadata>,
+    /// Ids of the verified users who annotated this referent.
+    /// > Only in `get_annotation`
+    pub verified_annotator_ids: Option<Vec<u32>>,
+    /// The document where the fragment was annotated.
+    /// > Only in `get_annotation`
+    pub annotatable: Option<Annotatable>,
+    /// > Empty in `get_annotation`
+    #[serde(default)]
     pub annotations: Vec<Annotation>,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct Annotatable {
+    pub api_path: String,
+    /// Context of the document, like the artist of a song.
+    pub context: Option<String>,
+    pub id: u32,
+    pub image_url: Option<String>,
+    pub link_title: String,
+    pub title: String,
+    /// Type of the document, like `Song` or `WebPage`.
+    #[serde(rename = "type")]
+    pub annotatable_type: String,
+    pub url: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct Annotation {
     pub api_path: String,
     pub body: Body,
     pub comment_count: Option<u32>,
     pub community: bool,
+    /// > Only in `get_annotation`
+    pub custom_preview: Option<String>,
     pub has_voters: bool,
     pub id: u32,
     pub pinned: bool,
@@ -40,13 +115,145 @@ pub struct Annotation {
     pub state: String,
     pub url: String,
     pub verified: bool,
-    pub votes_total: Option<u32>,
+    /// Upvotes minus downvotes, negative when there are more downvotes.
+    pub votes_total: Option<i32>,
     pub current_user_metadata: UserMetadata,
     pub authors: Vec<AnnotationAuthor>,
+    /// Users who cosigned this annotation.
+    /// > Only in `get_annotation`
+    pub cosigned_by: Option<Vec<User>>,
+    /// Why the annotation was rejected.
+    /// > Only in `get_annotation`
+    pub rejection_comment: Option<String>,
+    /// User who verified this annotation.
+    /// > Only in `get_annotation`
+    pub verified_by: Option<User>,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct AnnotationAuthor {
     pub attribution: f32,
     pub user: User,
 }
+
+/// Document of the referents of [`crate::Genius::get_referents`], a song and a web page can't be used together.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReferentsFor {
+    /// Id of a song.
+    Song(u32),
+    /// Id of a web page.
+    WebPage(u32),
+}
+
+/// Body of the request to create or update an annotation with [`crate::Genius::create_annotation`] and [`crate::Genius::update_annotation`].
+/// #### Examples
+/// Basic usage:
+/// ```
+/// use genius_rs::annotation::AnnotationRequest;
+///
+/// let request = AnnotationRequest::new(
+///     "hello **world!**",
+///     "http://seejohncode.com/2014/01/27/vim-commands-piping/",
+///     "execute commands",
+/// )
+/// .before_html("You may know that you can ")
+/// .after_html(" from inside of vim, with a vim command:");
+/// ```
+#[derive(Serialize, Debug)]
+pub struct AnnotationRequest {
+    annotation: AnnotationBody,
+    referent: ReferentRequest,
+    web_page: WebPageRequest,
+}
+
+#[derive(Serialize, Debug)]
+struct AnnotationBody {
+    body: Markdown,
+}
+
+#[derive(Serialize, Debug)]
+struct Markdown {
+    markdown: String,
+}
+
+#[derive(Serialize, Debug)]
+struct ReferentRequest {
+    raw_annotatable_url: String,
+    fragment: String,
+    context_for_display: ContextForDisplay,
+}
+
+#[derive(Serialize, Debug)]
+struct ContextForDisplay {
+    before_html: Option<String>,
+    after_html: Option<String>,
+}
+
+#[derive(Serialize, Debug)]
+struct WebPageRequest {
+    canonical_url: Option<String>,
+    og_url: Option<String>,
+    title: Option<String>,
+}
+
+impl AnnotationRequest {
+    /// `markdown` is the content of the annotation, `raw_annotatable_url` is the original URL of the page and `fragment` is the exact text of the page that will be annotated.
+    #[must_use]
+    pub fn new(markdown: &str, raw_annotatable_url: &str, fragment: &str) -> Self {
+        Self {
+            annotation: AnnotationBody {
+                body: Markdown {
+                    markdown: markdown.to_owned(),
+                },
+            },
+            referent: ReferentRequest {
+                raw_annotatable_url: raw_annotatable_url.to_owned(),
+                fragment: fragment.to_owned(),
+                context_for_display: ContextForDisplay {
+                    before_html: None,
+                    after_html: None,
+                },
+            },
+            web_page: WebPageRequest {
+                canonical_url: None,
+                og_url: None,
+                title: None,
+            },
+        }
+    }
+
+    /// The HTML before the fragment, used to find the fragment when it appears more than once in the page.
+    #[must_use]
+    pub fn before_html(mut self, before_html: &str) -> Self {
+        self.referent.context_for_display.before_html = Some(before_html.to_owned());
+        self
+    }
+
+    /// The HTML after the fragment, used to find the fragment when it appears more than once in the page.
+    #[must_use]
+    pub fn after_html(mut self, after_html: &str) -> Self {
+        self.referent.context_for_display.after_html = Some(after_html.to_owned());
+        self
+    }
+
+    /// The canonical URL of the page, from `<link rel="canonical">`.
+    #[must_use]
+    pub fn canonical_url(mut self, canonical_url: &str) -> Self {
+        self.web_page.canonical_url = Some(canonical_url.to_owned());
+        self
+    }
+
+    /// The Open Graph URL of the page, from `<meta property="og:url">`.
+    #[must_use]
+    pub fn og_url(mut self, og_url: &str) -> Self {
+        self.web_page.og_url = Some(og_url.to_owned());
+        self
+    }
+
+    /// The title of the page.
+    #[must_use]
+    pub fn title(mut self, title: &str) -> Self {
+        self.web_page.title = Some(title.to_owned());
+        self
+    }
+}
@@ -0,0 +1,70 @@
+use serde::{Deserialize, Serialize};
+
+use crate::annotation::Referent;
+use crate::user::{User, UserMetadata};
+use crate::Body;
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Artist {
+    /// Other names that the artist is known by.
+    pub alternate_names: Vec<String>,
+    /// Path of the API.
+    pub api_path: String,
+    /// Description of the artist.
+    pub description: Option<Body>,
+    /// Facebook username.
+    pub facebook_name: Option<String>,
+    /// Number of users that follow this artist.
+    pub followers_count: u32,
+    /// Artist header image.
+    pub header_image_url: String,
+    /// Artist id.
+    pub id: u32,
+    /// Artist image.
+    pub image_url: String,
+    /// Instagram username.
+    pub instagram_name: Option<String>,
+    /// Is this artist a meme?
+    pub is_meme_verified: bool,
+    /// If this artist is verified.
+    pub is_verified: bool,
+    /// Name of the artist.
+    pub name: String,
+    /// If this artist is a translation page of another artist.
+    pub translation_artist: bool,
+    /// Twitter username.
+    pub twitter_name: Option<String>,
+    /// Url of the artist page.
+    pub url: String,
+    /// How much iq this artist has.
+    pub iq: Option<u32>,
+    /// User permissions and interactions.
+    pub current_user_metadata: Option<UserMetadata>,
+    /// Description annotation.
+    pub description_annotation: Referent,
+    /// Genius account of the artist if it exists.
+    pub user: Option<User>,
+}
+
+/// Order of the songs returned by [`crate::Genius::artist_songs`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SongSort {
+    /// Alphabetical order of the song title.
+    Title,
+    /// Most viewed songs first.
+    Popularity,
+    /// Oldest songs first.
+    ReleaseDate,
+}
+
+impl SongSort {
+    /// Value of the `sort` query parameter.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Title => "title",
+            Self::Popularity => "popularity",
+            Self::ReleaseDate => "release_date",
+        }
+    }
+}
@@ -3,17 +3,63 @@ use serde::{Deserialize, Serialize};
 
 #[cfg(test)]
 mod test {
-    use crate::auth::auth_url;
+    use crate::auth::{auth_url, authenticate_at};
+    use wiremock::matchers::{body_partial_json, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
     #[test]
     fn auth_url_test() {
         let url = auth_url("my_client_id", "code", None, Some("me vote"), None);
         assert_eq!("https://api.genius.com/oauth/authorize?client_id=my_client_id&response_type=code&scope=me+vote", url.as_str());
     }
+
+    #[tokio::test]
+    async fn authenticate_test() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/oauth/token"))
+            .and(body_partial_json(serde_json::json!({
+                "code": "my_code",
+                "grant_type": "authorization_code",
+            })))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_string(include_str!("../tests/fixtures/oauth_token.json")),
+            )
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/oauth/token"))
+            .respond_with(
+                ResponseTemplate::new(400)
+                    .set_body_string(include_str!("../tests/fixtures/oauth_error.json")),
+            )
+            .mount(&server)
+            .await;
+        let url = format!("{}/oauth/token", server.uri());
+        let authenticate = |code: &str| {
+            authenticate_at(
+                &url,
+                code.to_owned(),
+                "my_client_secret".to_owned(),
+                "my_client_id".to_owned(),
+                "http://localhost/callback".to_owned(),
+            )
+        };
+        let auth = authenticate("my_code").await.unwrap();
+        assert_eq!(auth.token_type.as_deref(), Some("bearer"));
+        assert!(auth.access_token.is_some());
+        let auth = authenticate("expired_code").await.unwrap();
+        assert!(auth.access_token.is_none());
+        assert_eq!(auth.error.as_deref(), Some("invalid_grant"));
+    }
 }
 
 /// Authentication by login.
 pub mod login;
 
+const TOKEN_URL: &str = "https://api.genius.com/oauth/token";
+
 #[derive(Serialize)]
 struct AuthRequest {
     code: String,
@@ -84,6 +130,16 @@ pub async fn authenticate(
     client_secret: String,
     client_id: String,
     redirect_uri: String,
+) -> Result<AuthResponse, reqwest::Error> {
+    authenticate_at(TOKEN_URL, code, client_secret, client_id, redirect_uri).await
+}
+
+async fn authenticate_at(
+    url: &str,
+    code: String,
+    client_secret: String,
+    client_id: String,
+    redirect_uri: String,
 ) -> Result<AuthResponse, reqwest::Error> {
     let auth_req = AuthRequest {
         code,
@@ -93,10 +149,15 @@ pub async fn authenticate(
         response_type: "code".to_owned(),
         grant_type: "authorization_code".to_owned(),
     };
-    let url = Url::parse("https://api.genius.com/oauth/token")
-        .expect("Could not parse valid URL from login_with_username input.");
+    request_token(url, &auth_req).await
+}
+
+/// Send the `body` to the token endpoint at `url`.
+async fn request_token(
+    url: &str,
+    body: &(impl Serialize + Sync),
+) -> Result<AuthResponse, reqwest::Error> {
     let client = Client::new();
-    let request = client.post(url).json(&auth_req).send().await?;
-    let result = request.json::<AuthResponse>().await?;
-    Ok(result)
+    let request = client.post(url).json(body).send().await?;
+    request.json::<AuthResponse>().await
 }
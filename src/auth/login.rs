@@ -1,17 +1,20 @@
 use base64::decode;
 use chrono::offset::Utc;
 use hmac::{Hmac, Mac};
-use reqwest::{Client, Url};
 use serde::Serialize;
 use sha2::Sha256;
 
-use crate::auth::AuthResponse;
+use crate::auth::{request_token, AuthResponse, TOKEN_URL};
 
 #[cfg(test)]
 mod test {
-    use crate::auth::login::login_with_username;
+    use crate::auth::login::{login_at, login_with_username};
     use dotenv;
+    use wiremock::matchers::{body_partial_json, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
     #[tokio::test]
+    #[ignore = "needs LOG_USER, LOG_PASSW and network access"]
     async fn login_with_username_test() {
         let auth = login_with_username(
             &dotenv::var("LOG_USER").expect("Can't get username environment variable"),
@@ -21,6 +24,33 @@ mod test {
         .expect("Error requesting login with username");
         assert!(auth.access_token.is_some());
     }
+
+    #[tokio::test]
+    async fn login_test() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/oauth/token"))
+            .and(body_partial_json(serde_json::json!({
+                "username": "username",
+                "password": "password",
+                "grant_type": "password",
+            })))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_string(include_str!("../../tests/fixtures/oauth_token.json")),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+        let auth = login_at(
+            &format!("{}/oauth/token", server.uri()),
+            "username",
+            "password",
+        )
+        .await
+        .unwrap();
+        assert!(auth.access_token.is_some());
+    }
 }
 
 #[derive(Serialize)]
@@ -59,18 +89,16 @@ pub async fn login_with_username(
     username: &str,
     password: &str,
 ) -> Result<AuthResponse, reqwest::Error> {
-    let url = Url::parse("https://api.genius.com/oauth/token")
-        .expect("Could not parse valid URL from login_with_username input.");
+    login_at(TOKEN_URL, username, password).await
+}
+
+async fn login_at(
+    url: &str,
+    username: &str,
+    password: &str,
+) -> Result<AuthResponse, reqwest::Error> {
     let auth_request = username_auth_body(username, password);
-    let client = Client::new();
-    let res: AuthResponse = client
-        .post(url)
-        .json(&auth_request)
-        .send()
-        .await?
-        .json()
-        .await?;
-    Ok(res)
+    request_token(url, &auth_request).await
 }
 
 fn username_auth_body(username: &str, password: &str) -> AuthLoginRequest {
@@ -97,6 +125,6 @@ fn digest(username: &str, timestamp: &str) -> String {
         &decode("ZEVWWVpfcDVzX0tHY3Y0UGJJN015LWpjdXBhMHdWcTZJT081S1BqSzBKNjI2cXozWVA4OVphS1BTS3VHVDZONkQ1eTN1ZXc4WGVicnk4YmZXWkt5Rnc=")
             .expect("Unable to decode the key.")
     ).expect("An error occurred loading the key.");
-    mac.update(format!("{}{}", username, timestamp).as_bytes());
+    mac.update(format!("{username}{timestamp}").as_bytes());
     format!("{:x}", mac.finalize().into_bytes())
 }
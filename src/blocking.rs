@@ -0,0 +1,631 @@
+use reqwest::blocking::{Client, Request, RequestBuilder};
+use reqwest::header::USER_AGENT;
+use reqwest::Method;
+use std::time::Duration;
+
+use crate::album::Album;
+use crate::annotation::{Annotation, AnnotationRequest, Referent, ReferentsFor};
+use crate::artist::{Artist, SongSort};
+use crate::builder::GeniusBuilder;
+use crate::cache::CacheStats;
+use crate::endpoint::{self, Endpoint};
+use crate::error::GeniusError;
+use crate::matching::{self, SongMatch};
+use crate::retry::RetryPolicy;
+use crate::search::{Hit, SearchSection};
+use crate::song::{Song, SongPath};
+use crate::user::Account;
+use crate::web_page::WebPage;
+use crate::{Inner, TextFormat};
+
+/// Synchronous client for programs without an async runtime, it has the same methods as [`crate::Genius`] without `async`.
+///
+/// The streams of [`crate::Genius::search_stream`] and [`crate::Genius::artist_songs_stream`] are iterators in [`Genius::search_iter`] and [`Genius::artist_songs_iter`].
+/// Created with [`Genius::new`] or [`GeniusBuilder::build_blocking`], the retry policy, the rate limit and the cache of the builder work the same but block the thread.
+/// > Only with the `blocking` feature
+/// #### Examples
+/// Basic usage:
+/// ```no_run
+/// use genius_rs::blocking::Genius;
+///
+/// let genius = Genius::new(dotenv::var("TOKEN").unwrap());
+/// let response = genius.search("Ariana Grande").unwrap();
+/// println!("{}", response[0].song().unwrap().full_title);
+/// ```
+pub struct Genius {
+    pub(crate) reqwest: Client,
+    pub(crate) inner: Inner,
+}
+
+impl Genius {
+    /// Same as [`crate::Genius::new`].
+    #[must_use]
+    pub fn new(token: String) -> Self {
+        GeniusBuilder::new(token).build_blocking()
+    }
+
+    /// Same as [`crate::Genius::text_format`].
+    #[must_use]
+    pub fn text_format(&self) -> &[TextFormat] {
+        &self.inner.text_format
+    }
+
+    /// Same as [`crate::Genius::cache_stats`].
+    #[must_use]
+    pub fn cache_stats(&self) -> CacheStats {
+        self.inner.cache_stats()
+    }
+
+    /// Same as [`crate::Genius::search`].
+    ///
+    /// # Errors
+    ///
+    /// Same errors as [`crate::Genius::search`].
+    pub fn search(&self, q: &str) -> Result<Vec<Hit>, GeniusError> {
+        self.search_page(q, None, None)
+    }
+
+    /// Same as [`crate::Genius::search_page`].
+    ///
+    /// # Errors
+    ///
+    /// Same errors as [`crate::Genius::search`].
+    pub fn search_page(
+        &self,
+        q: &str,
+        per_page: Option<u32>,
+        page: Option<u32>,
+    ) -> Result<Vec<Hit>, GeniusError> {
+        self.call(&Endpoint::search(q, per_page, page))
+    }
+
+    /// Same as [`crate::Genius::search_stream`] but the pages are requested as the iterator is consumed.
+    ///
+    /// # Errors
+    ///
+    /// Yields the same errors as [`crate::Genius::search`], the iterator ends after the first error.
+    pub fn search_iter<'a>(
+        &'a self,
+        q: &'a str,
+        per_page: Option<u32>,
+    ) -> impl Iterator<Item = Result<Hit, GeniusError>> + 'a {
+        Pages::new(move |page| {
+            let hits = self.search_page(q, per_page, Some(page))?;
+            let next_page = if hits.is_empty() {
+                None
+            } else {
+                Some(page + 1)
+            };
+            Ok((hits, next_page))
+        })
+    }
+
+    /// Same as [`crate::Genius::search_multi`].
+    ///
+    /// # Errors
+    ///
+    /// Same errors as [`crate::Genius::search_multi`].
+    pub fn search_multi(&self, q: &str) -> Result<Vec<SearchSection>, GeniusError> {
+        self.call(&Endpoint::search_multi(q))
+    }
+
+    /// Same as [`crate::Genius::find_song`].
+    ///
+    /// # Errors
+    ///
+    /// Same errors as [`crate::Genius::search`].
+    pub fn find_song(&self, artist: &str, title: &str) -> Result<Option<SongMatch>, GeniusError> {
+        let hits = self.search(&matching::query(artist, title))?;
+        Ok(matching::best_match(artist, title, hits))
+    }
+
+    /// Same as [`crate::Genius::get_lyrics`].
+    /// > Only with the `lyrics` feature
+    ///
+    /// # Errors
+    ///
+    /// Same errors as [`crate::Genius::get_lyrics`].
+    #[cfg(feature = "lyrics")]
+    pub fn get_lyrics(&self, id: u32) -> Result<Vec<String>, GeniusError> {
+        let song = self.get_song(id, &self.inner.text_format)?;
+        let html = self.page(&song.path)?;
+        endpoint::lyrics_lines(&html)
+    }
+
+    /// Same as [`crate::Genius::get_song`].
+    ///
+    /// # Errors
+    ///
+    /// Same errors as [`crate::Genius::get_song`].
+    pub fn get_song(&self, id: u32, text_format: &[TextFormat]) -> Result<Song, GeniusError> {
+        let text_format = self.inner.text_format_param(text_format);
+        self.call(&Endpoint::song(id, text_format))
+    }
+
+    /// Same as [`crate::Genius::get_song_by_url`].
+    ///
+    /// # Errors
+    ///
+    /// Same errors as [`crate::Genius::get_song_by_url`].
+    pub fn get_song_by_url(
+        &self,
+        url: &str,
+        text_format: &[TextFormat],
+    ) -> Result<Song, GeniusError> {
+        let id = self.resolve_song_id(url)?;
+        self.get_song(id, text_format)
+    }
+
+    /// Same as [`crate::Genius::resolve_song_id`].
+    ///
+    /// # Errors
+    ///
+    /// Same errors as [`crate::Genius::resolve_song_id`].
+    pub fn resolve_song_id(&self, url: &str) -> Result<u32, GeniusError> {
+        let path = match SongPath::parse(url) {
+            Some(SongPath::Id(id)) => return Ok(id),
+            Some(SongPath::Page(path)) => path,
+            None => return Err(GeniusError::NotFound(format!("{url} is not a genius song"))),
+        };
+        let html = self.page(&path)?;
+        endpoint::page_song_id(&html, &path)
+    }
+
+    /// Same as [`crate::Genius::get_album`].
+    ///
+    /// # Errors
+    ///
+    /// Same errors as [`crate::Genius::get_album`].
+    pub fn get_album(&self, id: u32, text_format: &[TextFormat]) -> Result<Album, GeniusError> {
+        let text_format = self.inner.text_format_param(text_format);
+        self.call(&Endpoint::album(id, text_format))
+    }
+
+    /// Same as [`crate::Genius::get_artist`].
+    ///
+    /// # Errors
+    ///
+    /// Same errors as [`crate::Genius::get_artist`].
+    pub fn get_artist(&self, id: u32, text_format: &[TextFormat]) -> Result<Artist, GeniusError> {
+        let text_format = self.inner.text_format_param(text_format);
+        self.call(&Endpoint::artist(id, text_format))
+    }
+
+    /// Same as [`crate::Genius::artist_songs`].
+    ///
+    /// # Errors
+    ///
+    /// Same errors as [`crate::Genius::artist_songs`].
+    pub fn artist_songs(
+        &self,
+        artist_id: u32,
+        sort: SongSort,
+        per_page: Option<u32>,
+        page: Option<u32>,
+    ) -> Result<Vec<Song>, GeniusError> {
+        let (songs, _) = self.call(&Endpoint::artist_songs(artist_id, sort, per_page, page))?;
+        Ok(songs)
+    }
+
+    /// Same as [`crate::Genius::artist_songs_stream`] but the pages are requested as the iterator is consumed.
+    ///
+    /// # Errors
+    ///
+    /// Yields the same errors as [`crate::Genius::artist_songs`], the iterator ends after the first error.
+    pub fn artist_songs_iter(
+        &self,
+        artist_id: u32,
+        sort: SongSort,
+        per_page: Option<u32>,
+    ) -> impl Iterator<Item = Result<Song, GeniusError>> + '_ {
+        Pages::new(move |page| {
+            self.call(&Endpoint::artist_songs(
+                artist_id,
+                sort,
+                per_page,
+                Some(page),
+            ))
+        })
+    }
+
+    /// Same as [`crate::Genius::get_referents`].
+    ///
+    /// # Errors
+    ///
+    /// Same errors as [`crate::Genius::get_referents`].
+    pub fn get_referents(
+        &self,
+        document: Option<ReferentsFor>,
+        created_by_id: Option<u32>,
+        text_format: &[TextFormat],
+        per_page: Option<u32>,
+        page: Option<u32>,
+    ) -> Result<Vec<Referent>, GeniusError> {
+        let text_format = self.inner.text_format_param(text_format);
+        self.call(&Endpoint::referents(
+            document,
+            created_by_id,
+            text_format,
+            per_page,
+            page,
+        )?)
+    }
+
+    /// Same as [`crate::Genius::get_annotation`].
+    ///
+    /// # Errors
+    ///
+    /// Same errors as [`crate::Genius::get_annotation`].
+    pub fn get_annotation(
+        &self,
+        id: u32,
+        text_format: &[TextFormat],
+    ) -> Result<(Annotation, Referent), GeniusError> {
+        let text_format = self.inner.text_format_param(text_format);
+        self.call(&Endpoint::annotation(id, text_format))
+    }
+
+    /// Same as [`crate::Genius::create_annotation`].
+    ///
+    /// # Errors
+    ///
+    /// Same errors as [`crate::Genius::create_annotation`].
+    pub fn create_annotation(
+        &self,
+        annotation: &AnnotationRequest,
+        text_format: &[TextFormat],
+    ) -> Result<Annotation, GeniusError> {
+        let text_format = self.inner.text_format_param(text_format);
+        self.call(&Endpoint::create_annotation(annotation, text_format))
+    }
+
+    /// Same as [`crate::Genius::update_annotation`].
+    ///
+    /// # Errors
+    ///
+    /// Same errors as [`crate::Genius::update_annotation`].
+    pub fn update_annotation(
+        &self,
+        id: u32,
+        annotation: &AnnotationRequest,
+        text_format: &[TextFormat],
+    ) -> Result<Annotation, GeniusError> {
+        let text_format = self.inner.text_format_param(text_format);
+        self.call(&Endpoint::update_annotation(id, annotation, text_format))
+    }
+
+    /// Same as [`crate::Genius::delete_annotation`].
+    ///
+    /// # Errors
+    ///
+    /// Same errors as [`crate::Genius::delete_annotation`].
+    pub fn delete_annotation(&self, id: u32) -> Result<(), GeniusError> {
+        self.call(&Endpoint::delete_annotation(id))
+    }
+
+    /// Same as [`crate::Genius::upvote_annotation`].
+    ///
+    /// # Errors
+    ///
+    /// Same errors as [`crate::Genius::upvote_annotation`].
+    pub fn upvote_annotation(
+        &self,
+        id: u32,
+        text_format: &[TextFormat],
+    ) -> Result<Annotation, GeniusError> {
+        self.vote_annotation(id, "upvote", text_format)
+    }
+
+    /// Same as [`crate::Genius::downvote_annotation`].
+    ///
+    /// # Errors
+    ///
+    /// Same errors as [`crate::Genius::upvote_annotation`].
+    pub fn downvote_annotation(
+        &self,
+        id: u32,
+        text_format: &[TextFormat],
+    ) -> Result<Annotation, GeniusError> {
+        self.vote_annotation(id, "downvote", text_format)
+    }
+
+    /// Same as [`crate::Genius::unvote_annotation`].
+    ///
+    /// # Errors
+    ///
+    /// Same errors as [`crate::Genius::upvote_annotation`].
+    pub fn unvote_annotation(
+        &self,
+        id: u32,
+        text_format: &[TextFormat],
+    ) -> Result<Annotation, GeniusError> {
+        self.vote_annotation(id, "unvote", text_format)
+    }
+
+    /// Same as [`crate::Genius::get_account`].
+    /// Same as [`crate::Genius::get_account`].
+    ///
+    /// # Errors
+    ///
+    /// Same errors as [`crate::Genius::get_account`].
+    pub fn get_account(&self) -> Result<Account, GeniusError> {
+        self.call(&Endpoint::account())
+    }
+
+    /// Same as [`crate::Genius::lookup_web_page`].
+    ///
+    /// # Errors
+    ///
+    /// Same errors as [`crate::Genius::lookup_web_page`].
+    pub fn lookup_web_page(
+        &self,
+        raw_annotatable_url: Option<&str>,
+        canonical_url: Option<&str>,
+        og_url: Option<&str>,
+    ) -> Result<WebPage, GeniusError> {
+        self.call(&Endpoint::lookup_web_page(
+            raw_annotatable_url,
+            canonical_url,
+            og_url,
+        )?)
+    }
+
+    /// Same as `crate::Genius::call` blocking the thread.
+    fn call<T>(&self, endpoint: &Endpoint<'_, T>) -> Result<T, GeniusError> {
+        if let Some(value) = self.inner.cached(endpoint) {
+            return Ok(value);
+        }
+        let mut request = self
+            .request(endpoint.method.clone(), &endpoint.path)
+            .query(&endpoint.query);
+        if let Some(body) = endpoint.body {
+            request = request.json(body);
+        }
+        let body = self.send(request).map_err(endpoint.map_error)?;
+        let value = (endpoint.extract)(&body)?;
+        self.inner.store(endpoint, body);
+        Ok(value)
+    }
+
+    fn vote_annotation(
+        &self,
+        id: u32,
+        vote: &str,
+        text_format: &[TextFormat],
+    ) -> Result<Annotation, GeniusError> {
+        let text_format = self.inner.text_format_param(text_format);
+        self.call(&Endpoint::vote_annotation(id, vote, text_format))
+    }
+
+    /// Request a page of the Genius site with the `path` like `/Sia-chandelier-lyrics`.
+    fn page(&self, path: &str) -> Result<String, GeniusError> {
+        let request =
+            self.with_options(self.reqwest.get(format!("{}{path}", self.inner.lyrics_url)));
+        self.send(request)
+    }
+
+    fn request(&self, method: Method, path: &str) -> RequestBuilder {
+        let request = self
+            .reqwest
+            .request(method, format!("{}{path}", self.inner.base_url))
+            .bearer_auth(&self.inner.token);
+        self.with_options(request)
+    }
+
+    fn with_options(&self, mut request: RequestBuilder) -> RequestBuilder {
+        if let Some(user_agent) = &self.inner.user_agent {
+            request = request.header(USER_AGENT, user_agent);
+        }
+        if let Some(timeout) = self.inner.timeout {
+            request = request.timeout(timeout);
+        }
+        request
+    }
+
+    /// Same as `crate::Genius::send` sleeping the thread between the attempts.
+    fn send(&self, request: RequestBuilder) -> Result<String, GeniusError> {
+        let request = request.build()?;
+        let mut attempt = 1;
+        loop {
+            let retry = self
+                .inner
+                .retry
+                .as_ref()
+                .filter(|retry| retry.allows(request.method(), attempt));
+            let (Some(retry), Some(current)) = (retry, request.try_clone()) else {
+                return self.send_once(request).map_err(|(error, _)| error);
+            };
+            match self.send_once(current) {
+                Err((error, retry_after)) if RetryPolicy::is_transient(&error) => {
+                    let Some(delay) = retry.delay(attempt, retry_after) else {
+                        return Err(error);
+                    };
+                    std::thread::sleep(delay);
+                    attempt += 1;
+                }
+                result => return result.map_err(|(error, _)| error),
+            }
+        }
+    }
+
+    /// Send the request once, the error comes with the `Retry-After` header of the response.
+    fn send_once(&self, request: Request) -> Result<String, (GeniusError, Option<Duration>)> {
+        if let Some(rate_limiter) = &self.inner.rate_limiter {
+            rate_limiter.acquire_blocking();
+        }
+        let response = self
+            .reqwest
+            .execute(request)
+            .map_err(|e| (GeniusError::RequestError(e), None))?;
+        if let Some(source) = response.error_for_status_ref().err() {
+            let retry_after = GeniusError::retry_after(response.headers());
+            let status = response.status();
+            let body = response.text().unwrap_or_default();
+            let error = GeniusError::from_status(status, &body, retry_after)
+                .unwrap_or(GeniusError::RequestError(source));
+            return Err((error, retry_after));
+        }
+        response
+            .text()
+            .map_err(|e| (GeniusError::RequestError(e), None))
+    }
+}
+
+/// Items of the pages returned by `fetch` for a page number, starting from 1 and following the next page until there's none or `fetch` fails.
+struct Pages<T, F> {
+    fetch: F,
+    next_page: Option<u32>,
+    items: std::vec::IntoIter<T>,
+}
+
+impl<T, F> Pages<T, F>
+where
+    F: FnMut(u32) -> Result<(Vec<T>, Option<u32>), GeniusError>,
+{
+    fn new(fetch: F) -> Self {
+        Self {
+            fetch,
+            next_page: Some(1),
+            items: Vec::new().into_iter(),
+        }
+    }
+}
+
+impl<T, F> Iterator for Pages<T, F>
+where
+    F: FnMut(u32) -> Result<(Vec<T>, Option<u32>), GeniusError>,
+{
+    type Item = Result<T, GeniusError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(item) = self.items.next() {
+                return Some(Ok(item));
+            }
+            let page = self.next_page.take()?;
+            match (self.fetch)(page) {
+                Ok((items, next_page)) => {
+                    self.items = items.into_iter();
+                    self.next_page = next_page;
+                }
+                Err(error) => return Some(Err(error)),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::builder::GeniusBuilder;
+    use crate::cache::{CacheStats, MemoryCache};
+    use crate::retry::RetryPolicy;
+    use crate::TextFormat;
+    use std::time::Duration;
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[tokio::test]
+    async fn blocking_search_test() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/search"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_string(r#"{"meta":{"status":200},"response":{"hits":[]}}"#),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+        let uri = server.uri();
+        let hits = tokio::task::spawn_blocking(move || {
+            let genius = GeniusBuilder::new("token".to_owned())
+                .base_url(&uri)
+                .build_blocking();
+            genius.search("Sia")
+        })
+        .await
+        .unwrap()
+        .unwrap();
+        assert!(hits.is_empty());
+    }
+
+    #[tokio::test]
+    async fn blocking_search_iter_test() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/search"))
+            .and(query_param("page", "1"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_string(include_str!("../tests/fixtures/search.json")),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/search"))
+            .and(query_param("page", "2"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_string(r#"{"meta":{"status":200},"response":{"hits":[]}}"#),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+        let uri = server.uri();
+        let hits = tokio::task::spawn_blocking(move || {
+            let genius = GeniusBuilder::new("token".to_owned())
+                .base_url(&uri)
+                .build_blocking();
+            genius
+                .search_iter("Sia", None)
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .await
+        .unwrap()
+        .unwrap();
+        assert_eq!(hits.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn blocking_retry_cache_test() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/songs/378195"))
+            .respond_with(ResponseTemplate::new(503))
+            .up_to_n_times(1)
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/songs/378195"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_string(include_str!("../tests/fixtures/song.json")),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+        let uri = server.uri();
+        let stats = tokio::task::spawn_blocking(move || {
+            let genius = GeniusBuilder::new("token".to_owned())
+                .base_url(&uri)
+                .retry(
+                    RetryPolicy::new(2)
+                        .base_delay(Duration::from_millis(10))
+                        .jitter(Duration::ZERO),
+                )
+                .rate_limit(100.0, 2)
+                .cache(MemoryCache::new(10, Duration::from_secs(30)))
+                .build_blocking();
+            for _ in 0..2 {
+                let song = genius.get_song(378_195, &[TextFormat::Plain]).unwrap();
+                assert_eq!(song.title, "Chandelier");
+            }
+            genius.cache_stats()
+        })
+        .await
+        .unwrap();
+        assert_eq!(stats, CacheStats { hits: 1, misses: 1 });
+    }
+}
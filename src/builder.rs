@@ -0,0 +1,198 @@
+use reqwest::Client;
+use std::sync::atomic::AtomicU64;
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::cache::Cache;
+use crate::rate_limit::RateLimiter;
+use crate::retry::RetryPolicy;
+use crate::{Genius, Inner, TextFormat, LYRICS_URL, URL};
+
+/// Builder of a [`Genius`] client with custom options.
+/// #### Examples
+/// Basic usage:
+/// ```
+/// use genius_rs::builder::GeniusBuilder;
+/// use std::time::Duration;
+///
+/// let genius = GeniusBuilder::new("token".to_owned())
+///     .user_agent("my-app/1.0")
+///     .timeout(Duration::from_secs(10))
+///     .build();
+/// ```
+pub struct GeniusBuilder {
+    token: String,
+    client: Option<Client>,
+    #[cfg(feature = "blocking")]
+    blocking_client: Option<reqwest::blocking::Client>,
+    base_url: String,
+    lyrics_url: String,
+    text_format: Vec<TextFormat>,
+    user_agent: Option<String>,
+    timeout: Option<Duration>,
+    retry: Option<RetryPolicy>,
+    rate_limiter: Option<RateLimiter>,
+    cache: Option<Box<dyn Cache>>,
+}
+
+impl GeniusBuilder {
+    /// Create the builder with the token, see [`Genius::new`].
+    #[must_use]
+    pub fn new(token: String) -> Self {
+        Self {
+            token,
+            client: None,
+            #[cfg(feature = "blocking")]
+            blocking_client: None,
+            base_url: URL.to_owned(),
+            lyrics_url: LYRICS_URL.to_owned(),
+            text_format: vec![TextFormat::Plain],
+            user_agent: None,
+            timeout: None,
+            retry: None,
+            rate_limiter: None,
+            cache: None,
+        }
+    }
+
+    /// The url of the Genius API, the default is <https://api.genius.com>. Useful to point to a mock server.
+    #[must_use]
+    pub fn base_url(mut self, base_url: &str) -> Self {
+        base_url
+            .trim_end_matches('/')
+            .clone_into(&mut self.base_url);
+        self
+    }
+
+    /// The url of the song pages where the lyrics are scraped and the ids of [`Genius::resolve_song_id`] are read, the default is <https://genius.com>.
+    #[must_use]
+    pub fn lyrics_url(mut self, lyrics_url: &str) -> Self {
+        lyrics_url
+            .trim_end_matches('/')
+            .clone_into(&mut self.lyrics_url);
+        self
+    }
+
+    /// Use a [`reqwest::Client`] configured by you, like with a proxy. It's only used by [`GeniusBuilder::build`], see [`GeniusBuilder::blocking_client`] for the synchronous client.
+    #[must_use]
+    pub fn client(mut self, client: Client) -> Self {
+        self.client = Some(client);
+        self
+    }
+
+    /// Use a [`reqwest::blocking::Client`] configured by you in the client of [`GeniusBuilder::build_blocking`].
+    /// > Only with the `blocking` feature
+    #[cfg(feature = "blocking")]
+    #[must_use]
+    pub fn blocking_client(mut self, client: reqwest::blocking::Client) -> Self {
+        self.blocking_client = Some(client);
+        self
+    }
+
+    /// The `User-Agent` header sent in every request.
+    #[must_use]
+    pub fn user_agent(mut self, user_agent: &str) -> Self {
+        self.user_agent = Some(user_agent.to_owned());
+        self
+    }
+
+    /// The text format used when a method is called with an empty `text_format` and when this library requests a song by itself, the default is [`TextFormat::Plain`]. See [`Genius::text_format`].
+    #[must_use]
+    pub fn text_format(mut self, text_format: &[TextFormat]) -> Self {
+        text_format.clone_into(&mut self.text_format);
+        self
+    }
+
+    /// The timeout of each request, by default there's no timeout.
+    #[must_use]
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Retry the requests that fail with a transient error, by default the requests aren't retried. See [`RetryPolicy`].
+    #[must_use]
+    pub const fn retry(mut self, retry: RetryPolicy) -> Self {
+        self.retry = Some(retry);
+        self
+    }
+
+    /// Limit the requests sent by the client and its clones to `requests_per_second`, allowing a `burst` of requests at once. The requests wait until they can be sent, by default there's no limit.
+    #[must_use]
+    pub fn rate_limit(mut self, requests_per_second: f64, burst: u32) -> Self {
+        self.rate_limiter = Some(RateLimiter::new(requests_per_second, burst));
+        self
+    }
+
+    /// Cache the responses of [`Genius::get_song`], [`Genius::get_album`] and [`Genius::get_artist`], by default nothing is cached. See [`crate::cache::MemoryCache`].
+    #[must_use]
+    pub fn cache(mut self, cache: impl Cache + 'static) -> Self {
+        self.cache = Some(Box::new(cache));
+        self
+    }
+
+    /// Create the [`Genius`] client.
+    #[must_use]
+    pub fn build(mut self) -> Genius {
+        Genius {
+            reqwest: self.client.take().unwrap_or_default(),
+            inner: Arc::new(self.into_inner()),
+        }
+    }
+
+    /// Create the synchronous [`crate::blocking::Genius`] client with the same options as [`GeniusBuilder::build`].
+    /// The [`reqwest::Client`] of [`GeniusBuilder::client`] can't make synchronous requests so it's discarded, use [`GeniusBuilder::blocking_client`] instead.
+    /// > Only with the `blocking` feature
+    #[cfg(feature = "blocking")]
+    #[must_use]
+    pub fn build_blocking(mut self) -> crate::blocking::Genius {
+        crate::blocking::Genius {
+            reqwest: self.blocking_client.take().unwrap_or_default(),
+            inner: self.into_inner(),
+        }
+    }
+
+    fn into_inner(self) -> Inner {
+        Inner {
+            token: self.token,
+            base_url: self.base_url,
+            lyrics_url: self.lyrics_url,
+            text_format: self.text_format,
+            user_agent: self.user_agent,
+            timeout: self.timeout,
+            retry: self.retry,
+            rate_limiter: self.rate_limiter,
+            cache: self.cache,
+            cache_hits: AtomicU64::new(0),
+            cache_misses: AtomicU64::new(0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::builder::GeniusBuilder;
+    use crate::TextFormat;
+    use reqwest::header::{AUTHORIZATION, USER_AGENT};
+    use reqwest::Method;
+    use std::time::Duration;
+
+    #[test]
+    fn builder_test() {
+        let genius = GeniusBuilder::new("token".to_owned())
+            .base_url("http://localhost:8080/")
+            .user_agent("genius-rs-test")
+            .timeout(Duration::from_secs(5))
+            .text_format(&[TextFormat::Html, TextFormat::Dom])
+            .build();
+        let request = genius
+            .request(Method::GET, "/songs/378195")
+            .build()
+            .unwrap();
+        assert_eq!(request.url().as_str(), "http://localhost:8080/songs/378195");
+        assert_eq!(request.headers()[USER_AGENT], "genius-rs-test");
+        assert_eq!(request.headers()[AUTHORIZATION], "Bearer token");
+        assert_eq!(request.timeout(), Some(&Duration::from_secs(5)));
+        assert_eq!(genius.text_format(), [TextFormat::Html, TextFormat::Dom]);
+    }
+}
@@ -0,0 +1,245 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+#[cfg(test)]
+mod test {
+    use crate::cache::{Cache, CacheKey, MemoryCache};
+    use std::time::Duration;
+
+    fn key(id: u32) -> CacheKey {
+        CacheKey {
+            endpoint: "songs",
+            id,
+            text_format: "plain".to_owned(),
+        }
+    }
+
+    #[test]
+    fn memory_cache_test() {
+        let cache = MemoryCache::new(2, Duration::from_secs(30));
+        cache.insert(&key(1), "one".to_owned());
+        cache.insert(&key(2), "two".to_owned());
+        assert_eq!(cache.get(&key(1)), Some("one".to_owned()));
+        cache.insert(&key(3), "three".to_owned());
+        assert_eq!(cache.get(&key(2)), None);
+        assert_eq!(cache.get(&key(1)), Some("one".to_owned()));
+        assert_eq!(cache.get(&key(3)), Some("three".to_owned()));
+        assert_eq!(key(1).to_string(), "songs-1-plain");
+    }
+
+    #[test]
+    fn memory_cache_ttl_test() {
+        let cache = MemoryCache::new(2, Duration::ZERO);
+        cache.insert(&key(1), "one".to_owned());
+        assert_eq!(cache.get(&key(1)), None);
+    }
+
+    #[cfg(feature = "disk-cache")]
+    #[test]
+    fn disk_cache_test() {
+        use crate::cache::DiskCache;
+
+        let dir = std::env::temp_dir().join(format!("genius-rs-{}", std::process::id()));
+        let cache = DiskCache::new(&dir, Duration::from_secs(30));
+        assert_eq!(cache.get(&key(1)), None);
+        cache.insert(&key(1), "one".to_owned());
+        assert_eq!(cache.get(&key(1)), Some("one".to_owned()));
+        cache.insert(&key(1), "uno".to_owned());
+        assert_eq!(cache.get(&key(1)), Some("uno".to_owned()));
+        assert_eq!(std::fs::read_dir(&dir).unwrap().count(), 1);
+        assert_eq!(DiskCache::new(&dir, Duration::ZERO).get(&key(1)), None);
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
+
+/// Storage of the response bodies of [`crate::Genius::get_song`], [`crate::Genius::get_album`] and [`crate::Genius::get_artist`], set with [`crate::builder::GeniusBuilder::cache`].
+///
+/// A cached response is returned without requesting the Genius API. The implementations are responsible of expiring old responses.
+pub trait Cache: Send + Sync {
+    /// The response body stored with `key`, `None` if there's no body or it expired.
+    fn get(&self, key: &CacheKey) -> Option<String>;
+    /// Store the response `body` with `key`.
+    fn insert(&self, key: &CacheKey, body: String);
+}
+
+/// Key of a cached response, its [`fmt::Display`] like `songs-378195-plain_html` can be used as a file name to store the response outside of memory.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CacheKey {
+    /// The endpoint like `songs`, `albums` or `artists`.
+    pub(crate) endpoint: &'static str,
+    /// The id of the document.
+    pub(crate) id: u32,
+    /// The `text_format` query parameter, like `plain,html`.
+    pub(crate) text_format: String,
+}
+
+impl fmt::Display for CacheKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}-{}-{}",
+            self.endpoint,
+            self.id,
+            self.text_format.replace(',', "_")
+        )
+    }
+}
+
+/// Number of requests answered by the cache, see [`crate::Genius::cache_stats`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CacheStats {
+    /// Responses found in the cache.
+    pub hits: u64,
+    /// Responses not found in the cache, requested to the Genius API.
+    pub misses: u64,
+}
+
+/// In memory [`Cache`] that keeps the `capacity` most recently used responses for `ttl`.
+/// #### Examples
+/// Basic usage:
+/// ```
+/// use genius_rs::cache::MemoryCache;
+/// use genius_rs::Genius;
+/// use std::time::Duration;
+///
+/// let genius = Genius::builder("token".to_owned())
+///     .cache(MemoryCache::new(1000, Duration::from_secs(60 * 60)))
+///     .build();
+/// ```
+#[derive(Debug)]
+pub struct MemoryCache {
+    capacity: usize,
+    ttl: Duration,
+    entries: Mutex<Entries>,
+}
+
+#[derive(Debug, Default)]
+struct Entries {
+    map: HashMap<CacheKey, Entry>,
+    tick: u64,
+}
+
+#[derive(Debug)]
+struct Entry {
+    body: String,
+    inserted_at: Instant,
+    used_at: u64,
+}
+
+impl MemoryCache {
+    /// `capacity` is the maximum number of responses, when it's full the least recently used response is removed. `ttl` is how long a response is kept.
+    #[must_use]
+    pub fn new(capacity: usize, ttl: Duration) -> Self {
+        Self {
+            capacity,
+            ttl,
+            entries: Mutex::default(),
+        }
+    }
+
+    fn entries(&self) -> std::sync::MutexGuard<'_, Entries> {
+        self.entries
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+}
+
+impl Cache for MemoryCache {
+    fn get(&self, key: &CacheKey) -> Option<String> {
+        let mut entries = self.entries();
+        entries.tick += 1;
+        let tick = entries.tick;
+        let entry = entries.map.get_mut(key)?;
+        if entry.inserted_at.elapsed() >= self.ttl {
+            entries.map.remove(key);
+            return None;
+        }
+        entry.used_at = tick;
+        let body = entry.body.clone();
+        drop(entries);
+        Some(body)
+    }
+
+    fn insert(&self, key: &CacheKey, body: String) {
+        if self.capacity == 0 {
+            return;
+        }
+        let mut entries = self.entries();
+        entries.tick += 1;
+        let tick = entries.tick;
+        if !entries.map.contains_key(key) && entries.map.len() >= self.capacity {
+            let oldest = entries
+                .map
+                .iter()
+                .min_by_key(|(_, entry)| entry.used_at)
+                .map(|(key, _)| key.clone());
+            if let Some(oldest) = oldest {
+                entries.map.remove(&oldest);
+            }
+        }
+        entries.map.insert(
+            key.clone(),
+            Entry {
+                body,
+                inserted_at: Instant::now(),
+                used_at: tick,
+            },
+        );
+    }
+}
+
+/// On disk [`Cache`] that keeps the responses as files in a directory for `ttl`, so they survive between runs.
+/// > Only with the `disk-cache` feature
+#[cfg(feature = "disk-cache")]
+#[derive(Debug)]
+pub struct DiskCache {
+    dir: std::path::PathBuf,
+    ttl: Duration,
+}
+
+#[cfg(feature = "disk-cache")]
+impl DiskCache {
+    /// `dir` is the directory of the files, it's created when the first response is stored. `ttl` is how long a response is kept.
+    #[must_use]
+    pub fn new(dir: impl Into<std::path::PathBuf>, ttl: Duration) -> Self {
+        Self {
+            dir: dir.into(),
+            ttl,
+        }
+    }
+
+    fn path(&self, key: &CacheKey) -> std::path::PathBuf {
+        self.dir.join(format!("{key}.json"))
+    }
+}
+
+#[cfg(feature = "disk-cache")]
+impl Cache for DiskCache {
+    fn get(&self, key: &CacheKey) -> Option<String> {
+        let path = self.path(key);
+        let age = std::fs::metadata(&path)
+            .ok()?
+            .modified()
+            .ok()?
+            .elapsed()
+            .ok()?;
+        if age >= self.ttl {
+            std::fs::remove_file(path).ok();
+            return None;
+        }
+        std::fs::read_to_string(path).ok()
+    }
+
+    /// The body is written to a temporary file renamed to the file of `key`, so readers never see a file written halfway.
+    fn insert(&self, key: &CacheKey, body: String) {
+        if std::fs::create_dir_all(&self.dir).is_err() {
+            return;
+        }
+        let temp = self.dir.join(format!(".{key}.{}.tmp", fastrand::u64(..)));
+        if std::fs::write(&temp, body).is_err() || std::fs::rename(&temp, self.path(key)).is_err() {
+            std::fs::remove_file(temp).ok();
+        }
+    }
+}
@@ -0,0 +1,274 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap as Map;
+
+#[cfg(test)]
+mod test {
+    use crate::dom::DomNode;
+
+    fn dom() -> DomNode {
+        serde_json::from_str(
+            r#"{
+                "tag": "root",
+                "children": [
+                    {"tag": "h2", "children": ["About"]},
+                    {"tag": "p", "children": [
+                        "\"Chandelier\" is by ",
+                        {"tag": "a", "attributes": {"href": "https://genius.com/artists/Sia", "rel": "noopener"}, "data": {"api_path": "/artists/16775"}, "children": ["Sia"]},
+                        ",",
+                        {"tag": "br"},
+                        {"tag": "em", "children": ["a <party> song"]},
+                        " and ",
+                        {"tag": "strong", "children": ["a hit"]}
+                    ]},
+                    {"tag": "ol", "children": [
+                        {"tag": "li", "children": ["One"]},
+                        {"tag": "li", "children": ["Two"]}
+                    ]}
+                ]
+            }"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn to_plain_test() {
+        assert_eq!(
+            dom().to_plain(),
+            "About\n\n\"Chandelier\" is by Sia,\na <party> song and a hit\n\nOne\nTwo"
+        );
+    }
+
+    #[test]
+    fn to_html_test() {
+        assert_eq!(
+            dom().to_html(),
+            "<h2>About</h2><p>&quot;Chandelier&quot; is by <a href=\"https://genius.com/artists/Sia\" rel=\"noopener\">Sia</a>,<br><em>a &lt;party&gt; song</em> and <strong>a hit</strong></p><ol><li>One</li><li>Two</li></ol>"
+        );
+    }
+
+    #[test]
+    fn to_markdown_test() {
+        assert_eq!(
+            dom().to_markdown(),
+            "## About\n\n\"Chandelier\" is by [Sia](https://genius.com/artists/Sia),\n*a <party> song* and **a hit**\n\n1. One\n2. Two"
+        );
+    }
+
+    #[test]
+    fn to_ansi_test() {
+        assert_eq!(
+            dom().to_ansi(),
+            "\x1b[1mAbout\x1b[22m\n\n\"Chandelier\" is by \x1b[4mSia\x1b[24m,\n\x1b[3ma <party> song\x1b[23m and \x1b[1ma hit\x1b[22m\n\nOne\nTwo"
+        );
+    }
+}
+
+/// Node of the tree returned in [`crate::Body::dom`] when requested with [`crate::TextFormat::Dom`].
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(untagged)]
+pub enum DomNode {
+    /// Text without markup.
+    Text(String),
+    /// HTML element like `p`, `a` or `em`, the root of the tree has the tag `root`.
+    Element {
+        tag: String,
+        #[serde(default)]
+        attributes: Map<String, Value>,
+        #[serde(default)]
+        children: Vec<Self>,
+    },
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Style {
+    Plain,
+    Markdown,
+    Ansi,
+}
+
+const BLOCKS: [&str; 3] = ["p", "div", "blockquote"];
+const VOID: [&str; 3] = ["br", "hr", "img"];
+
+impl DomNode {
+    /// Render the tree in plain text, paragraphs are separated by an empty line.
+    #[must_use]
+    pub fn to_plain(&self) -> String {
+        self.render(Style::Plain)
+    }
+
+    /// Render the tree in HTML, the `root` tag is not rendered.
+    #[must_use]
+    pub fn to_html(&self) -> String {
+        let mut html = String::new();
+        self.write_html(&mut html);
+        html
+    }
+
+    /// Render the tree in Markdown, links keep their `href` and images their `src`.
+    #[must_use]
+    pub fn to_markdown(&self) -> String {
+        self.render(Style::Markdown)
+    }
+
+    /// Render the tree in plain text styled with ANSI escape codes for terminals, bold and headings are bold, italic is italic and links are underlined.
+    #[must_use]
+    pub fn to_ansi(&self) -> String {
+        self.render(Style::Ansi)
+    }
+
+    fn render(&self, style: Style) -> String {
+        let mut text = String::new();
+        self.write_text(style, &mut text);
+        text.trim_end().to_owned()
+    }
+
+    fn write_text(&self, style: Style, text: &mut String) {
+        let (tag, attributes, children) = match self {
+            Self::Text(t) => {
+                text.push_str(t);
+                return;
+            }
+            Self::Element {
+                tag,
+                attributes,
+                children,
+            } => (tag.as_str(), attributes, children),
+        };
+        let inner = |style| {
+            let mut inner = String::new();
+            for child in children {
+                child.write_text(style, &mut inner);
+            }
+            inner
+        };
+        match (style, tag) {
+            (_, "br") => text.push('\n'),
+            (Style::Markdown, "hr") => text.push_str("---\n\n"),
+            (Style::Markdown, "img") => {
+                let alt = attribute(attributes, "alt").unwrap_or_default();
+                let src = attribute(attributes, "src").unwrap_or_default();
+                wrap(text, "![", &alt, &format!("]({src})"));
+            }
+            (Style::Markdown, "em" | "i") => wrap(text, "*", &inner(style), "*"),
+            (Style::Markdown, "strong" | "b") => wrap(text, "**", &inner(style), "**"),
+            (Style::Markdown, "code") => wrap(text, "`", &inner(style), "`"),
+            (Style::Markdown, "a") => {
+                let href = attribute(attributes, "href").unwrap_or_default();
+                wrap(text, "[", &inner(style), &format!("]({href})"));
+            }
+            (Style::Markdown, "blockquote") => {
+                for line in inner(style).trim_end().lines() {
+                    wrap(text, "> ", line, "\n");
+                }
+                text.push('\n');
+            }
+            (Style::Markdown, h) if heading_level(h).is_some() => {
+                let level = heading_level(h).unwrap_or(1);
+                wrap(
+                    text,
+                    &format!("{} ", "#".repeat(level)),
+                    &inner(style),
+                    "\n\n",
+                );
+            }
+            (Style::Ansi, "em" | "i") => wrap(text, "\x1b[3m", &inner(style), "\x1b[23m"),
+            (Style::Ansi, "strong" | "b") => {
+                wrap(text, "\x1b[1m", &inner(style), "\x1b[22m");
+            }
+            (Style::Ansi, "a") => wrap(text, "\x1b[4m", &inner(style), "\x1b[24m"),
+            (Style::Ansi, h) if heading_level(h).is_some() => {
+                wrap(text, "\x1b[1m", &inner(style), "\x1b[22m\n\n");
+            }
+            (_, "ul" | "ol") => {
+                let items = children.iter().filter(|child| child.tag() == Some("li"));
+                for (i, item) in items.enumerate() {
+                    match (style, tag) {
+                        (Style::Markdown, "ol") => wrap(text, "", &(i + 1).to_string(), ". "),
+                        (Style::Markdown, _) => text.push_str("- "),
+                        _ => {}
+                    }
+                    text.push_str(item.render(style).as_str());
+                    text.push('\n');
+                }
+                text.push('\n');
+            }
+            (_, t) if BLOCKS.contains(&t) || heading_level(t).is_some() => {
+                text.push_str(inner(style).trim_end());
+                text.push_str("\n\n");
+            }
+            _ => text.push_str(&inner(style)),
+        }
+    }
+
+    fn write_html(&self, html: &mut String) {
+        match self {
+            Self::Text(t) => html.push_str(&escape(t)),
+            Self::Element { tag, children, .. } if tag == "root" => {
+                for child in children {
+                    child.write_html(html);
+                }
+            }
+            Self::Element {
+                tag,
+                attributes,
+                children,
+            } => {
+                html.push('<');
+                html.push_str(tag);
+                for name in attributes.keys() {
+                    let value = attribute(attributes, name).unwrap_or_default();
+                    wrap(html, &format!(" {name}=\""), &escape(&value), "\"");
+                }
+                html.push('>');
+                if VOID.contains(&tag.as_str()) {
+                    return;
+                }
+                for child in children {
+                    child.write_html(html);
+                }
+                wrap(html, "</", tag, ">");
+            }
+        }
+    }
+
+    fn tag(&self) -> Option<&str> {
+        match self {
+            Self::Text(_) => None,
+            Self::Element { tag, .. } => Some(tag),
+        }
+    }
+}
+
+fn attribute(attributes: &Map<String, Value>, name: &str) -> Option<String> {
+    attributes.get(name).map(|value| {
+        value
+            .as_str()
+            .map_or_else(|| value.to_string(), str::to_owned)
+    })
+}
+
+fn heading_level(tag: &str) -> Option<usize> {
+    match tag {
+        "h1" => Some(1),
+        "h2" => Some(2),
+        "h3" => Some(3),
+        "h4" => Some(4),
+        "h5" => Some(5),
+        "h6" => Some(6),
+        _ => None,
+    }
+}
+
+fn wrap(text: &mut String, before: &str, inner: &str, after: &str) {
+    text.push_str(before);
+    text.push_str(inner);
+    text.push_str(after);
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
@@ -0,0 +1,303 @@
+use reqwest::Method;
+
+use crate::album::Album;
+use crate::annotation::{Annotation, AnnotationRequest, Referent, ReferentsFor};
+use crate::artist::{Artist, SongSort};
+use crate::cache::CacheKey;
+use crate::error::GeniusError;
+use crate::search::{Hit, SearchSection};
+use crate::song::{Song, SongPath};
+use crate::user::Account;
+use crate::web_page::WebPage;
+use crate::{BlobResponse, Response};
+
+/// Request to the Genius API and how its response is read, the same for [`crate::Genius`] and the synchronous client.
+///
+/// The `text_format` of the constructors is the value of the query parameter, already defaulted by the client.
+pub struct Endpoint<'a, T> {
+    pub method: Method,
+    pub path: String,
+    pub query: Vec<(&'static str, String)>,
+    /// JSON body of the request.
+    pub body: Option<&'a AnnotationRequest>,
+    /// Key of the response in the cache, `None` if the response isn't cached.
+    pub cache_key: Option<CacheKey>,
+    /// Read the result from the response body.
+    pub extract: fn(&str) -> Result<T, GeniusError>,
+    /// Change the error of the request, like the status of a missing scope.
+    pub map_error: fn(GeniusError) -> GeniusError,
+}
+
+impl<'a, T> Endpoint<'a, T> {
+    fn new(method: Method, path: String, extract: fn(&str) -> Result<T, GeniusError>) -> Self {
+        Self {
+            method,
+            path,
+            query: vec![],
+            body: None,
+            cache_key: None,
+            extract,
+            map_error: |error| error,
+        }
+    }
+
+    fn query(mut self, query: Vec<(&'static str, String)>) -> Self {
+        self.query = query;
+        self
+    }
+
+    fn text_format(self, text_format: String) -> Self {
+        self.query(vec![("text_format", text_format)])
+    }
+
+    const fn body(mut self, body: &'a AnnotationRequest) -> Self {
+        self.body = Some(body);
+        self
+    }
+}
+
+impl Endpoint<'_, Vec<Hit>> {
+    pub fn search(q: &str, per_page: Option<u32>, page: Option<u32>) -> Self {
+        Self::new(Method::GET, "/search".to_owned(), |body| {
+            found(parse(body)?.hits, "Hits")
+        })
+        .query(page_params(vec![("q", q.to_owned())], per_page, page))
+    }
+}
+
+impl Endpoint<'_, Vec<SearchSection>> {
+    pub fn search_multi(q: &str) -> Self {
+        Self::new(Method::GET, "/search/multi".to_owned(), |body| {
+            found(parse(body)?.sections, "Sections")
+        })
+        .query(vec![("q", q.to_owned())])
+    }
+}
+
+impl Endpoint<'_, Song> {
+    pub fn song(id: u32, text_format: String) -> Self {
+        Self::document("songs", id, text_format, |body| {
+            found(parse(body)?.song, "Song")
+        })
+    }
+}
+
+impl Endpoint<'_, Album> {
+    pub fn album(id: u32, text_format: String) -> Self {
+        Self::document("albums", id, text_format, |body| {
+            found(parse(body)?.album, "Album")
+        })
+    }
+}
+
+impl Endpoint<'_, Artist> {
+    pub fn artist(id: u32, text_format: String) -> Self {
+        Self::document("artists", id, text_format, |body| {
+            found(parse(body)?.artist, "Artist")
+        })
+    }
+}
+
+impl<T> Endpoint<'_, T> {
+    /// Cached document of the `endpoint` like `/songs/378195`.
+    fn document(
+        endpoint: &'static str,
+        id: u32,
+        text_format: String,
+        extract: fn(&str) -> Result<T, GeniusError>,
+    ) -> Self {
+        let mut document = Self::new(Method::GET, format!("/{endpoint}/{id}"), extract)
+            .text_format(text_format.clone());
+        document.cache_key = Some(CacheKey {
+            endpoint,
+            id,
+            text_format,
+        });
+        document
+    }
+}
+
+impl Endpoint<'_, (Vec<Song>, Option<u32>)> {
+    /// The songs and the `next_page`.
+    pub fn artist_songs(
+        artist_id: u32,
+        sort: SongSort,
+        per_page: Option<u32>,
+        page: Option<u32>,
+    ) -> Self {
+        Self::new(Method::GET, format!("/artists/{artist_id}/songs"), |body| {
+            let res = parse(body)?;
+            Ok((found(res.songs, "Songs")?, res.next_page))
+        })
+        .query(page_params(
+            vec![("sort", sort.as_str().to_owned())],
+            per_page,
+            page,
+        ))
+    }
+}
+
+impl Endpoint<'_, Vec<Referent>> {
+    pub fn referents(
+        document: Option<ReferentsFor>,
+        created_by_id: Option<u32>,
+        text_format: String,
+        per_page: Option<u32>,
+        page: Option<u32>,
+    ) -> Result<Self, GeniusError> {
+        if document.is_none() && created_by_id.is_none() {
+            return Err(GeniusError::InvalidArguments(
+                "A document or a created_by_id is required".to_owned(),
+            ));
+        }
+        let mut params = vec![("text_format", text_format)];
+        match document {
+            Some(ReferentsFor::Song(song_id)) => params.push(("song_id", song_id.to_string())),
+            Some(ReferentsFor::WebPage(web_page_id)) => {
+                params.push(("web_page_id", web_page_id.to_string()));
+            }
+            None => {}
+        }
+        if let Some(created_by_id) = created_by_id {
+            params.push(("created_by_id", created_by_id.to_string()));
+        }
+        Ok(Self::new(Method::GET, "/referents".to_owned(), |body| {
+            found(parse(body)?.referents, "Referents")
+        })
+        .query(page_params(params, per_page, page)))
+    }
+}
+
+impl Endpoint<'_, (Annotation, Referent)> {
+    pub fn annotation(id: u32, text_format: String) -> Self {
+        Self::new(Method::GET, format!("/annotations/{id}"), |body| {
+            let res = parse(body)?;
+            Ok((
+                found(res.annotation, "Annotation")?,
+                found(res.referent, "Referent")?,
+            ))
+        })
+        .text_format(text_format)
+    }
+}
+
+impl<'a> Endpoint<'a, Annotation> {
+    pub fn create_annotation(annotation: &'a AnnotationRequest, text_format: String) -> Self {
+        Self::new(Method::POST, "/annotations".to_owned(), annotation_body)
+            .text_format(text_format)
+            .body(annotation)
+    }
+
+    pub fn update_annotation(
+        id: u32,
+        annotation: &'a AnnotationRequest,
+        text_format: String,
+    ) -> Self {
+        Self::new(Method::PUT, format!("/annotations/{id}"), annotation_body)
+            .text_format(text_format)
+            .body(annotation)
+    }
+
+    /// `vote` is `upvote`, `downvote` or `unvote`.
+    pub fn vote_annotation(id: u32, vote: &str, text_format: String) -> Self {
+        Self::new(
+            Method::PUT,
+            format!("/annotations/{id}/{vote}"),
+            annotation_body,
+        )
+        .text_format(text_format)
+    }
+}
+
+impl Endpoint<'_, ()> {
+    /// The response body isn't read.
+    pub fn delete_annotation(id: u32) -> Self {
+        Self::new(Method::DELETE, format!("/annotations/{id}"), |_| Ok(()))
+    }
+}
+
+impl Endpoint<'_, Account> {
+    /// A missing `me` scope is [`GeniusError::Unauthorized`], as it was before [`GeniusError::Forbidden`] existed.
+    pub fn account() -> Self {
+        let mut account = Self::new(Method::GET, "/account".to_owned(), |body| {
+            found(parse(body)?.user, "User")
+        });
+        account.map_error = |error| match error {
+            GeniusError::Forbidden(message) => GeniusError::Unauthorized(message),
+            error => error,
+        };
+        account
+    }
+}
+
+impl Endpoint<'_, WebPage> {
+    pub fn lookup_web_page(
+        raw_annotatable_url: Option<&str>,
+        canonical_url: Option<&str>,
+        og_url: Option<&str>,
+    ) -> Result<Self, GeniusError> {
+        let params: Vec<(&str, String)> = [
+            ("raw_annotatable_url", raw_annotatable_url),
+            ("canonical_url", canonical_url),
+            ("og_url", og_url),
+        ]
+        .iter()
+        .filter_map(|&(name, url)| url.map(|url| (name, url.to_owned())))
+        .collect();
+        if params.is_empty() {
+            return Err(GeniusError::InvalidArguments(
+                "At least one url is required".to_owned(),
+            ));
+        }
+        Ok(
+            Self::new(Method::GET, "/web_pages/lookup".to_owned(), |body| {
+                found(parse(body)?.web_page, "Web page")
+            })
+            .query(params),
+        )
+    }
+}
+
+/// Id of the song of the song page `html` requested with `path`.
+pub fn page_song_id(html: &str, path: &str) -> Result<u32, GeniusError> {
+    SongPath::page_song_id(html)
+        .ok_or_else(|| GeniusError::NotFound(format!("Song id not found in the page {path}")))
+}
+
+/// Lines of the lyrics of the song page `html`.
+#[cfg(feature = "lyrics")]
+pub fn lyrics_lines(html: &str) -> Result<Vec<String>, GeniusError> {
+    let lyrics = crate::lyrics::parse_lyrics(html)
+        .ok_or_else(|| GeniusError::NotFound("Lyrics not found in page".to_owned()))?;
+    Ok(lyrics.split('\n').map(String::from).collect())
+}
+
+/// Add the `per_page` and `page` query parameters to `params`.
+fn page_params(
+    mut params: Vec<(&'static str, String)>,
+    per_page: Option<u32>,
+    page: Option<u32>,
+) -> Vec<(&'static str, String)> {
+    if let Some(per_page) = per_page {
+        params.push(("per_page", per_page.to_string()));
+    }
+    if let Some(page) = page {
+        params.push(("page", page.to_string()));
+    }
+    params
+}
+
+fn parse(body: &str) -> Result<BlobResponse, GeniusError> {
+    serde_json::from_str::<Response>(body)
+        .map(|res| res.response)
+        .map_err(|e| GeniusError::ParseError(e.to_string()))
+}
+
+/// The `field` of the response, [`GeniusError::NotFound`] if it's empty.
+fn found<T>(field: Option<T>, name: &str) -> Result<T, GeniusError> {
+    field.ok_or_else(|| GeniusError::NotFound(format!("{name} not found in data")))
+}
+
+fn annotation_body(body: &str) -> Result<Annotation, GeniusError> {
+    found(parse(body)?.annotation, "Annotation")
+}
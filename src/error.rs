@@ -1,15 +1,157 @@
+use reqwest::header::{HeaderMap, RETRY_AFTER};
+use reqwest::StatusCode;
+use serde::Deserialize;
+use std::time::{Duration, SystemTime};
 use thiserror::Error;
 
+#[cfg(test)]
+mod test {
+    use crate::error::GeniusError;
+    use reqwest::StatusCode;
+    use std::time::Duration;
+
+    #[test]
+    fn from_status_test() {
+        let error = GeniusError::from_status(
+            StatusCode::NOT_FOUND,
+            r#"{"meta":{"status":404,"message":"Not found"}}"#,
+            None,
+        );
+        assert!(matches!(error, Some(GeniusError::NotFound(m)) if m == "Not found"));
+        let error = GeniusError::from_status(
+            StatusCode::UNAUTHORIZED,
+            r#"{"error":"invalid_token","error_description":"The access token provided is invalid."}"#,
+            None,
+        );
+        assert!(
+            matches!(error, Some(GeniusError::Unauthorized(m)) if m == "The access token provided is invalid.")
+        );
+        let error = GeniusError::from_status(
+            StatusCode::TOO_MANY_REQUESTS,
+            "",
+            Some(Duration::from_secs(3)),
+        );
+        assert!(matches!(
+            error,
+            Some(GeniusError::RateLimited { message, retry_after: Some(d) }) if message == "Too Many Requests" && d.as_secs() == 3
+        ));
+        let error = GeniusError::from_status(StatusCode::BAD_GATEWAY, "<html></html>", None);
+        assert!(matches!(error, Some(GeniusError::InternalServerError(_))));
+        assert!(GeniusError::from_status(StatusCode::BAD_REQUEST, "", None).is_none());
+    }
+
+    #[test]
+    fn retry_after_test() {
+        use reqwest::header::{HeaderMap, HeaderValue, RETRY_AFTER};
+        use std::time::SystemTime;
+
+        let mut headers = HeaderMap::new();
+        assert_eq!(GeniusError::retry_after(&headers), None);
+        headers.insert(RETRY_AFTER, HeaderValue::from_static("90"));
+        assert_eq!(
+            GeniusError::retry_after(&headers),
+            Some(Duration::from_secs(90))
+        );
+        let date = httpdate::fmt_http_date(SystemTime::now() + Duration::from_secs(30));
+        headers.insert(RETRY_AFTER, HeaderValue::from_str(&date).unwrap());
+        let retry_after = GeniusError::retry_after(&headers).unwrap();
+        assert!(retry_after > Duration::from_secs(25) && retry_after <= Duration::from_secs(30));
+        headers.insert(
+            RETRY_AFTER,
+            HeaderValue::from_static("Wed, 21 Oct 2015 07:28:00 GMT"),
+        );
+        assert_eq!(GeniusError::retry_after(&headers), Some(Duration::ZERO));
+    }
+}
+
 #[derive(Debug, Error)]
 pub enum GeniusError {
+    /// The server failed with a 5xx status.
     #[error("{0}")]
     InternalServerError(String),
     #[error("Parse error: {0}")]
     ParseError(String),
+    /// The request could not be sent or the server answered with an unexpected status.
     #[error("Request error: {0}")]
-    RequestError(String),
+    RequestError(#[from] reqwest::Error),
+    /// The token is invalid, the status is 401.
     #[error("Unauthorized: {0}")]
     Unauthorized(String),
+    /// The token doesn't have the scope required by the endpoint, the status is 403.
+    /// [`crate::Genius::get_account`] still returns [`GeniusError::Unauthorized`] for its missing `me` scope.
+    #[error("Forbidden: {0}")]
+    Forbidden(String),
+    /// The resource doesn't exist, the status is 404, or a field is missing in the response.
     #[error("Not found: {0}")]
     NotFound(String),
+    /// The arguments of the method are invalid, the request isn't sent.
+    #[error("Invalid arguments: {0}")]
+    InvalidArguments(String),
+    /// The request was understood but its content is invalid, the status is 422.
+    #[error("Unprocessable entity: {0}")]
+    UnprocessableEntity(String),
+    /// Too many requests were made, the status is 429. `retry_after` comes from the `Retry-After` header.
+    #[error("Rate limited: {message}")]
+    RateLimited {
+        message: String,
+        retry_after: Option<Duration>,
+    },
+}
+
+#[derive(Deserialize)]
+struct ErrorResponse {
+    meta: Option<Meta>,
+    error_description: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct Meta {
+    status: Option<u16>,
+    message: Option<String>,
+}
+
+impl GeniusError {
+    /// Error of a response with an error `status`, the `meta` envelope of the `body` is preferred over the HTTP status.
+    /// Returns `None` for statuses without a variant.
+    pub(crate) fn from_status(
+        status: StatusCode,
+        body: &str,
+        retry_after: Option<Duration>,
+    ) -> Option<Self> {
+        let response = serde_json::from_str::<ErrorResponse>(body).ok();
+        let meta = response.as_ref().and_then(|res| res.meta.as_ref());
+        let status = meta
+            .and_then(|meta| meta.status)
+            .and_then(|status| StatusCode::from_u16(status).ok())
+            .unwrap_or(status);
+        let message = meta
+            .and_then(|meta| meta.message.clone())
+            .or_else(|| response.and_then(|res| res.error_description))
+            .unwrap_or_else(|| status.canonical_reason().unwrap_or_default().to_owned());
+        match status {
+            StatusCode::UNAUTHORIZED => Some(Self::Unauthorized(message)),
+            StatusCode::FORBIDDEN => Some(Self::Forbidden(message)),
+            StatusCode::NOT_FOUND => Some(Self::NotFound(message)),
+            StatusCode::UNPROCESSABLE_ENTITY => Some(Self::UnprocessableEntity(message)),
+            StatusCode::TOO_MANY_REQUESTS => Some(Self::RateLimited {
+                message,
+                retry_after,
+            }),
+            s if s.is_server_error() => Some(Self::InternalServerError(message)),
+            _ => None,
+        }
+    }
+
+    /// Time to wait before sending another request from the `Retry-After` header, in seconds or an HTTP date.
+    pub(crate) fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+        let value = headers.get(RETRY_AFTER)?.to_str().ok()?.trim();
+        if let Ok(seconds) = value.parse() {
+            return Some(Duration::from_secs(seconds));
+        }
+        let date = httpdate::parse_http_date(value).ok()?;
+        Some(
+            date.duration_since(SystemTime::now())
+                .unwrap_or(Duration::ZERO),
+        )
+    }
 }
@@ -5,43 +5,27 @@
 //!  Create an API Client at <https://genius.com/developers> and get the token to get Genius API access.
 //! ## Searching for a Song
 //!
-//! ```rust
+//! ```no_run
 //! use genius_rs::Genius;
 //!
 //! #[tokio::main]
 //! async fn main() {
 //!     let genius = Genius::new(dotenv::var("TOKEN").unwrap());
 //!     let response = genius.search("Ariana Grande").await.unwrap();
-//!     println!("{}", response[0].result.full_title);
-//! }
-//! ```
-//!
-//! ## Getting lyrics
-//!
-//! ```rust
-//! use genius_rs::Genius;
-//!
-//! #[tokio::main]
-//! async fn main() {
-//!     let genius = Genius::new(dotenv::var("TOKEN").unwrap());
-//!     let response = genius.search("Sia").await.unwrap();
-//!     let lyrics = genius.get_lyrics(response[0].result.id).await.unwrap();
-//!     for verse in lyrics {
-//!         println!("{}", verse);
-//!     }
+//!     println!("{}", response[0].song().unwrap().full_title);
 //! }
 //! ```
 //!
 //! ## Getting deeper information for a song by id
 //!
-//! ```rust
-//! use genius_rs::Genius;
+//! ```no_run
+//! use genius_rs::{Genius, TextFormat};
 //!
 //! #[tokio::main]
 //! async fn main() {
 //!     let genius = Genius::new(dotenv::var("TOKEN").unwrap());
 //!     let response = genius.search("Weeknd").await.unwrap();
-//!     let song = genius.get_song(response[0].result.id, "plain").await.unwrap();
+//!     let song = genius.get_song(response[0].song().unwrap().id, &[TextFormat::Plain]).await.unwrap();
 //!     println!("{}", song.media.unwrap()[0].url)
 //! }
 //! ```
@@ -53,73 +37,850 @@
 pub mod album;
 /// Annotation response
 pub mod annotation;
+/// Artist response
+pub mod artist;
 /// Authentication methods
 pub mod auth;
+/// Synchronous client
+#[cfg(feature = "blocking")]
+pub mod blocking;
+/// Client builder
+pub mod builder;
+/// Response cache
+pub mod cache;
+/// DOM text format
+pub mod dom;
+/// Requests shared by the clients
+mod endpoint;
 /// Error response
 pub mod error;
+/// Lyrics parsing
+pub mod lyrics;
+/// Song matching
+pub mod matching;
+/// Client side rate limit
+mod rate_limit;
+/// Retry policy
+pub mod retry;
 /// Search response
 pub mod search;
 /// Song response
 pub mod song;
 /// User response
 pub mod user;
+/// Web page response
+pub mod web_page;
 
 use album::Album;
+use annotation::{Annotation, AnnotationRequest, Referent, ReferentsFor};
+use artist::{Artist, SongSort};
+use builder::GeniusBuilder;
+use cache::{Cache, CacheStats};
+use dom::DomNode;
+use endpoint::Endpoint;
 use error::GeniusError;
-use reqwest::Client;
-use search::Hit;
-use serde::Deserialize;
-use song::Song;
+use futures::stream::{self, Stream, TryStreamExt};
+use matching::SongMatch;
+use rate_limit::RateLimiter;
+use reqwest::header::USER_AGENT;
+use reqwest::{Client, Method, Request, RequestBuilder};
+use retry::RetryPolicy;
+use search::{Hit, SearchSection};
+use serde::{Deserialize, Serialize};
+use song::{Song, SongPath};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use user::Account;
+use web_page::WebPage;
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    /// Server answering with the recorded responses of `tests/fixtures`, as requested with a `user-core` token.
+    async fn fixture_server() -> MockServer {
+        let server = MockServer::start().await;
+        let fixtures = [
+            ("/search", include_str!("../tests/fixtures/search.json")),
+            (
+                "/search/multi",
+                include_str!("../tests/fixtures/search_multi.json"),
+            ),
+            ("/songs/378195", include_str!("../tests/fixtures/song.json")),
+            ("/account", include_str!("../tests/fixtures/account.json")),
+            (
+                "/albums/104614",
+                include_str!("../tests/fixtures/album.json"),
+            ),
+            (
+                "/Sia-chandelier-lyrics",
+                include_str!("../tests/fixtures/song_page.html"),
+            ),
+        ];
+        for (fixture_path, body) in fixtures {
+            Mock::given(method("GET"))
+                .and(path(fixture_path))
+                .respond_with(ResponseTemplate::new(200).set_body_string(body))
+                .mount(&server)
+                .await;
+        }
+        server
+    }
+
+    fn fixture_genius(server: &MockServer) -> Genius {
+        Genius::builder("token".to_owned())
+            .base_url(&server.uri())
+            .lyrics_url(&server.uri())
+            .build()
+    }
 
     #[tokio::test]
+    #[ignore = "needs a TOKEN and network access"]
     async fn search_test() {
         let genius = Genius::new(dotenv::var("TOKEN").unwrap());
         let result = genius.search("Ariana Grande").await;
         assert!(result.is_ok());
     }
 
+    #[cfg(feature = "lyrics")]
     #[tokio::test]
+    #[ignore = "needs a TOKEN and network access"]
     async fn get_lyrics_test() {
         let genius = Genius::new(dotenv::var("TOKEN").unwrap());
         let lyrics = genius.get_lyrics(1).await.unwrap();
         for verse in lyrics {
-            println!("{}", verse);
+            println!("{verse}");
         }
     }
 
     #[tokio::test]
+    #[ignore = "needs a TOKEN and network access"]
     async fn get_song_test() {
         let genius = Genius::new(dotenv::var("TOKEN").unwrap());
-        genius.get_song(378_195, "plain").await.unwrap();
+        genius
+            .get_song(378_195, &[TextFormat::Plain])
+            .await
+            .unwrap();
     }
 
     #[tokio::test]
+    #[ignore = "needs a TOKEN and network access"]
+    async fn get_song_by_url_test() {
+        let genius = Genius::new(dotenv::var("TOKEN").unwrap());
+        let song = genius
+            .get_song_by_url(
+                "https://genius.com/Sia-chandelier-lyrics",
+                &[TextFormat::Plain],
+            )
+            .await
+            .unwrap();
+        assert_eq!(song.path, "/Sia-chandelier-lyrics");
+    }
+
+    #[tokio::test]
+    #[ignore = "needs a TOKEN and network access"]
     async fn get_album_test() {
         let genius = Genius::new(dotenv::var("TOKEN").unwrap());
-        genius.get_album(27501, "plain").await.unwrap();
+        genius.get_album(27501, &[TextFormat::Plain]).await.unwrap();
+    }
+
+    #[tokio::test]
+    #[ignore = "needs a TOKEN and network access"]
+    async fn get_artist_test() {
+        let genius = Genius::new(dotenv::var("TOKEN").unwrap());
+        genius
+            .get_artist(16775, &[TextFormat::Plain])
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    #[ignore = "needs a TOKEN and network access"]
+    async fn artist_songs_test() {
+        let genius = Genius::new(dotenv::var("TOKEN").unwrap());
+        let songs = genius
+            .artist_songs(16775, SongSort::Popularity, Some(5), None)
+            .await
+            .unwrap();
+        assert_eq!(songs.len(), 5);
+    }
+
+    #[tokio::test]
+    #[ignore = "needs a TOKEN and network access"]
+    async fn artist_songs_stream_test() {
+        use futures::StreamExt;
+
+        let genius = Genius::new(dotenv::var("TOKEN").unwrap());
+        let songs: Vec<_> = genius
+            .artist_songs_stream(16775, SongSort::Title, Some(2))
+            .take(5)
+            .collect()
+            .await;
+        assert_eq!(songs.len(), 5);
+        assert!(songs.iter().all(Result::is_ok));
+    }
+
+    #[tokio::test]
+    #[ignore = "needs a TOKEN and network access"]
+    async fn get_referents_test() {
+        let genius = Genius::new(dotenv::var("TOKEN").unwrap());
+        let referents = genius
+            .get_referents(
+                Some(ReferentsFor::Song(378_195)),
+                None,
+                &[TextFormat::Plain],
+                Some(5),
+                None,
+            )
+            .await
+            .unwrap();
+        assert!(referents.len() <= 5);
+    }
+
+    #[tokio::test]
+    #[ignore = "needs a TOKEN and network access"]
+    async fn get_annotation_test() {
+        let genius = Genius::new(dotenv::var("TOKEN").unwrap());
+        let (annotation, _) = genius
+            .get_annotation(10_225_840, &[TextFormat::Plain])
+            .await
+            .unwrap();
+        assert_eq!(annotation.id, 10_225_840);
+    }
+
+    #[tokio::test]
+    #[ignore = "needs LOG_USER, LOG_PASSW and network access"]
+    async fn get_account_test() {
+        let auth = auth::login::login_with_username(
+            &dotenv::var("LOG_USER").unwrap(),
+            &dotenv::var("LOG_PASSW").unwrap(),
+        )
+        .await
+        .unwrap();
+        let genius = Genius::new(auth.access_token.unwrap());
+        let account = genius.get_account().await.unwrap();
+        assert_eq!(account.user.login, dotenv::var("LOG_USER").unwrap());
+    }
+
+    #[tokio::test]
+    #[ignore = "needs a TOKEN and network access"]
+    async fn lookup_web_page_test() {
+        let genius = Genius::new(dotenv::var("TOKEN").unwrap());
+        let web_page = genius
+            .lookup_web_page(
+                Some("http://seejohncode.com/2014/01/27/vim-commands-piping/"),
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+        genius
+            .get_referents(
+                Some(ReferentsFor::WebPage(web_page.id)),
+                None,
+                &[TextFormat::Plain],
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn retry_test() {
+        use retry::RetryPolicy;
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/search"))
+            .respond_with(ResponseTemplate::new(503))
+            .up_to_n_times(2)
+            .expect(2)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/search"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_string(r#"{"meta":{"status":200},"response":{"hits":[]}}"#),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("PUT"))
+            .respond_with(ResponseTemplate::new(503))
+            .expect(1)
+            .mount(&server)
+            .await;
+        let genius = Genius::builder("token".to_owned())
+            .base_url(&server.uri())
+            .retry(
+                RetryPolicy::new(3)
+                    .base_delay(Duration::from_millis(10))
+                    .jitter(Duration::ZERO),
+            )
+            .build();
+        let hits = genius.search("Sia").await.unwrap();
+        assert!(hits.is_empty());
+        let error = genius
+            .upvote_annotation(10_225_840, &[TextFormat::Plain])
+            .await;
+        assert!(matches!(error, Err(GeniusError::InternalServerError(_))));
+    }
+
+    #[tokio::test]
+    async fn retry_after_max_delay_test() {
+        use retry::RetryPolicy;
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/search"))
+            .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "86400"))
+            .expect(1)
+            .mount(&server)
+            .await;
+        let genius = Genius::builder("token".to_owned())
+            .base_url(&server.uri())
+            .retry(RetryPolicy::new(3).max_delay(Duration::from_secs(5)))
+            .build();
+        let error = genius.search("Sia").await;
+        assert!(matches!(
+            error,
+            Err(GeniusError::RateLimited { retry_after: Some(d), .. }) if d.as_secs() == 86400
+        ));
+    }
+
+    #[tokio::test]
+    async fn vote_annotation_test() {
+        let server = MockServer::start().await;
+        for (vote, votes_total, interaction) in [
+            ("upvote", 1, serde_json::json!("up")),
+            ("downvote", -2, serde_json::json!("down")),
+            ("unvote", -1, serde_json::Value::Null),
+        ] {
+            Mock::given(method("PUT"))
+                .and(path(format!("/annotations/10225840/{vote}")))
+                .and(query_param("text_format", "plain"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                    "meta": { "status": 200 },
+                    "response": { "annotation": {
+                        "api_path": "/annotations/10225840", "body": { "plain": "hello world!" },
+                        "comment_count": 0, "community": true, "has_voters": true, "id": 10_225_840,
+                        "pinned": false, "share_url": "", "state": "accepted", "url": "",
+                        "verified": false, "votes_total": votes_total, "authors": [],
+                        "current_user_metadata": {
+                            "permissions": [], "excluded_permissions": [],
+                            "interactions": { "cosign": false, "pyong": false, "vote": interaction }
+                        }
+                    }}
+                })))
+                .expect(1)
+                .mount(&server)
+                .await;
+        }
+        let genius = fixture_genius(&server);
+        let format = [TextFormat::Plain];
+        let vote = |annotation: &Annotation| {
+            annotation
+                .current_user_metadata
+                .interactions
+                .as_ref()
+                .unwrap()
+                .vote
+                .clone()
+        };
+        let annotation = genius.upvote_annotation(10_225_840, &format).await.unwrap();
+        assert_eq!(annotation.votes_total, Some(1));
+        assert_eq!(vote(&annotation).as_deref(), Some("up"));
+        let annotation = genius
+            .downvote_annotation(10_225_840, &format)
+            .await
+            .unwrap();
+        assert_eq!(annotation.votes_total, Some(-2));
+        assert_eq!(vote(&annotation).as_deref(), Some("down"));
+        let annotation = genius.unvote_annotation(10_225_840, &format).await.unwrap();
+        assert_eq!(annotation.votes_total, Some(-1));
+        assert_eq!(vote(&annotation), None);
+    }
+
+    #[tokio::test]
+    async fn rate_limit_test() {
+        use std::time::Instant;
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_string(r#"{"meta":{"status":200},"response":{"hits":[]}}"#),
+            )
+            .expect(3)
+            .mount(&server)
+            .await;
+        let genius = Genius::builder("token".to_owned())
+            .base_url(&server.uri())
+            .rate_limit(20.0, 1)
+            .build();
+        let clone = genius.clone();
+        let start = Instant::now();
+        genius.search("Sia").await.unwrap();
+        clone.search("Sia").await.unwrap();
+        genius.search("Sia").await.unwrap();
+        assert!(start.elapsed() >= Duration::from_millis(90));
+    }
+
+    #[tokio::test]
+    async fn cache_test() {
+        use cache::{CacheKey, MemoryCache};
+
+        let server = MockServer::start().await;
+        let song = include_str!("../tests/fixtures/song.json");
+        Mock::given(method("GET"))
+            .and(path("/songs/378195"))
+            .and(query_param("text_format", "plain"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(song))
+            .expect(1)
+            .mount(&server)
+            .await;
+        let cache = MemoryCache::new(10, Duration::from_secs(30));
+        let key = CacheKey {
+            endpoint: "songs",
+            id: 378_195,
+            text_format: "plain".to_owned(),
+        };
+        cache.insert(&key, song[..song.len() / 2].to_owned());
+        let genius = Genius::builder("token".to_owned())
+            .base_url(&server.uri())
+            .cache(cache)
+            .build();
+        for _ in 0..2 {
+            let song = genius
+                .get_song(378_195, &[TextFormat::Plain])
+                .await
+                .unwrap();
+            assert_eq!(song.title, "Chandelier");
+        }
+        assert_eq!(genius.cache_stats(), CacheStats { hits: 1, misses: 1 });
+    }
+
+    #[tokio::test]
+    async fn search_fixture_test() {
+        let server = fixture_server().await;
+        let hits = fixture_genius(&server).search("Sia").await.unwrap();
+        assert_eq!(hits.len(), 2);
+        for hit in hits {
+            let artist = &hit.song().unwrap().primary_artist;
+            assert_eq!(artist.index_character, Some('s'));
+            assert_eq!(artist.slug.as_deref(), Some("Sia"));
+        }
+    }
+
+    #[tokio::test]
+    async fn search_stream_test() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/search"))
+            .and(query_param("q", "Beyoncé & Jay-Z #1+"))
+            .and(query_param("page", "1"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_string(include_str!("../tests/fixtures/search.json")),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/search"))
+            .and(query_param("page", "2"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_string(r#"{"meta":{"status":200},"response":{"hits":[]}}"#),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+        let genius = fixture_genius(&server);
+        let hits: Vec<Hit> = genius
+            .search_stream("Beyoncé & Jay-Z #1+", Some(2))
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(hits.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn artist_songs_stream_fixture_test() {
+        let server = MockServer::start().await;
+        let fixture: serde_json::Value =
+            serde_json::from_str(include_str!("../tests/fixtures/song.json")).unwrap();
+        let song = &fixture["response"]["song"];
+        for (page, next_page) in [("1", serde_json::json!(2)), ("2", serde_json::Value::Null)] {
+            Mock::given(method("GET"))
+                .and(path("/artists/16775/songs"))
+                .and(query_param("sort", "title"))
+                .and(query_param("per_page", "2"))
+                .and(query_param("page", page))
+                .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                    "meta": { "status": 200 },
+                    "response": { "songs": [song, song], "next_page": next_page }
+                })))
+                .expect(1)
+                .mount(&server)
+                .await;
+        }
+        let songs: Vec<Song> = fixture_genius(&server)
+            .artist_songs_stream(16775, SongSort::Title, Some(2))
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(songs.len(), 4);
+        assert!(songs.iter().all(|song| song.id == 378_195));
+    }
+
+    #[tokio::test]
+    async fn search_multi_fixture_test() {
+        use search::SectionType;
+
+        let server = fixture_server().await;
+        let sections = fixture_genius(&server).search_multi("Sia").await.unwrap();
+        let types: Vec<SectionType> = sections.iter().map(|s| s.section_type).collect();
+        assert_eq!(
+            types,
+            [
+                SectionType::TopHit,
+                SectionType::Song,
+                SectionType::Lyric,
+                SectionType::Artist,
+                SectionType::Album,
+                SectionType::Video,
+                SectionType::Article,
+                SectionType::User
+            ]
+        );
+        assert!(matches!(&sections[0].hits[0], Hit::Song(song) if song.id == 378_195));
+        assert!(matches!(&sections[3].hits[0], Hit::Artist(artist) if artist.name == "Sia"));
+        assert!(matches!(&sections[4].hits[0], Hit::Album(album) if album.id == 104_614));
+        assert!(matches!(&sections[5].hits[0], Hit::Video(_)));
+        assert!(matches!(&sections[6].hits[0], Hit::Article(_)));
+        assert!(matches!(&sections[7].hits[0], Hit::User(user) if user.login == "sia-fan"));
+    }
+
+    #[tokio::test]
+    async fn find_song_fixture_test() {
+        let server = fixture_server().await;
+        let genius = fixture_genius(&server);
+        let found = genius
+            .find_song("SIA feat. Sean Paul", "Cheap Thrills - 2016 Remaster")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(found.song.title, "Cheap Thrills");
+        assert!((found.confidence - 1.0).abs() < f64::EPSILON);
+        for artist in ["Sia, Sean Paul", "Sia & Sean Paul"] {
+            let found = genius
+                .find_song(artist, "Cheap Thrills")
+                .await
+                .unwrap()
+                .unwrap();
+            assert_eq!(found.song.title, "Cheap Thrills");
+            assert!((found.confidence - 1.0).abs() < f64::EPSILON);
+        }
+        let found = genius.find_song("Sia", "Chandelir").await.unwrap().unwrap();
+        assert_eq!(found.song.title, "Chandelier");
+        assert!(found.confidence < 1.0);
+        assert!(genius
+            .find_song("Metallica", "One")
+            .await
+            .unwrap()
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn get_referents_document_test() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/referents"))
+            .and(query_param("web_page_id", "10347"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_string(r#"{"meta":{"status":200},"response":{"referents":[]}}"#),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+        let referents = fixture_genius(&server)
+            .get_referents(
+                Some(ReferentsFor::WebPage(10347)),
+                None,
+                &[TextFormat::Plain],
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+        assert!(referents.is_empty());
+    }
+
+    #[tokio::test]
+    async fn get_referents_without_document_test() {
+        let server = MockServer::start().await;
+        let result = fixture_genius(&server)
+            .get_referents(None, None, &[TextFormat::Plain], None, None)
+            .await;
+        assert!(matches!(result, Err(GeniusError::InvalidArguments(_))));
+        assert!(server.received_requests().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn lookup_web_page_without_url_test() {
+        let server = MockServer::start().await;
+        let result = fixture_genius(&server)
+            .lookup_web_page(None, None, None)
+            .await;
+        assert!(matches!(result, Err(GeniusError::InvalidArguments(_))));
+        assert!(server.received_requests().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn default_text_format_test() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/songs/378195"))
+            .and(query_param("text_format", "html,dom"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_string(include_str!("../tests/fixtures/song.json")),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+        let genius = Genius::builder("token".to_owned())
+            .base_url(&server.uri())
+            .text_format(&[TextFormat::Html, TextFormat::Dom])
+            .build();
+        genius.get_song(378_195, &[]).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn get_song_fixture_test() {
+        let server = fixture_server().await;
+        let song = fixture_genius(&server)
+            .get_song(378_195, &[TextFormat::Plain])
+            .await
+            .unwrap();
+        assert!(song.comment_count.is_some());
+        assert!(song.custom_header_image_url.is_some());
+        assert!(song.custom_song_art_image_url.is_some());
+        assert!(song.description_preview.is_some());
+        assert!(song.facebook_share_message_without_url.is_some());
+        assert!(song.has_instagram_reel_annotations.is_some());
+        assert!(song.hidden.is_some());
+        assert!(song.instrumental.is_some());
+        assert!(song.is_music.is_some());
+        assert!(song.lyrics.unwrap().plain.is_some());
+        assert!(song.lyrics_updated_at.is_some());
+        assert!(song.pending_lyrics_edits_count.is_some());
+        assert!(song.published.is_some());
+        assert!(song.pusher_channel.is_some());
+        assert_eq!(
+            song.release_date_components,
+            Some(Date {
+                year: Some(2014),
+                month: Some(3),
+                day: Some(17)
+            })
+        );
+        assert!(song.share_url.is_some());
+        assert!(song.soundcloud_url.is_some());
+        assert!(song.spotify_uuid.is_some());
+        assert!(song.tracking_paths.is_some());
+        assert!(song.twitter_share_message.is_some());
+        assert!(song.twitter_share_message_without_url.is_some());
+        assert!(song.updated_by_human_at.is_some());
+        assert!(song.viewable_by_roles.is_some());
+        assert!(song.youtube_start.is_some());
+        assert!(song.youtube_url.is_some());
+        assert!(!song.albums.unwrap().is_empty());
+        let album = song.album.unwrap();
+        assert!(album.cover_art_thumbnail_url.is_some());
+        assert!(album.name_with_artist.is_some());
+        assert!(album.release_date_components.is_some());
+        let referent = song.description_annotation.unwrap();
+        assert!(referent.ios_app_url.is_some());
+        assert!(referent.is_image.is_some());
+        assert!(referent.current_user_metadata.is_some());
+        assert!(song.primary_artist.index_character.is_some());
+        assert!(song.primary_artist.slug.is_some());
+    }
+
+    #[tokio::test]
+    async fn get_account_fixture_test() {
+        let server = fixture_server().await;
+        let account = fixture_genius(&server).get_account().await.unwrap();
+        assert_eq!(account.user.login, "sia-fan");
+        assert_eq!(account.user.iq, Some(1234));
+        assert_eq!(account.email.as_deref(), Some("sia-fan@example.com"));
+        assert_eq!(account.iq_for_display.as_deref(), Some("1,234"));
+        assert_eq!(account.unread_newsfeed_inbox_count, Some(9));
+        assert_eq!(account.stats.unwrap().annotations_count, Some(5));
+    }
+
+    #[tokio::test]
+    async fn get_account_without_scope_test() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/account"))
+            .respond_with(ResponseTemplate::new(403).set_body_string(
+                r#"{"error":"insufficient_scope","error_description":"The request requires the me scope."}"#,
+            ))
+            .mount(&server)
+            .await;
+        let result = fixture_genius(&server).get_account().await;
+        assert!(
+            matches!(result, Err(GeniusError::Unauthorized(m)) if m == "The request requires the me scope.")
+        );
+    }
+
+    #[tokio::test]
+    async fn get_song_by_url_fixture_test() {
+        let server = fixture_server().await;
+        let genius = fixture_genius(&server);
+        let song = genius
+            .get_song_by_url(
+                "https://genius.com/Sia-chandelier-lyrics",
+                &[TextFormat::Plain],
+            )
+            .await
+            .unwrap();
+        assert_eq!(song.id, 378_195);
+        let result = genius.resolve_song_id("/Sia-unknown-lyrics").await;
+        assert!(matches!(result, Err(GeniusError::NotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn get_album_fixture_test() {
+        let server = fixture_server().await;
+        let album = fixture_genius(&server)
+            .get_album(104_614, &[TextFormat::Plain])
+            .await
+            .unwrap();
+        assert_eq!(
+            album.cover_art_thumbnail_url.as_deref(),
+            Some("https://images.genius.com/1000-forms-of-fear.300x300x1.jpg")
+        );
+        assert_eq!(
+            album.name_with_artist.as_deref(),
+            Some("1000 Forms of Fear (artist: Sia)")
+        );
+        assert!(album.release_date_components.is_some());
+        assert!(album.artist.index_character.is_some());
+        assert!(album.artist.slug.is_some());
+    }
+
+    #[cfg(feature = "lyrics")]
+    #[tokio::test]
+    async fn get_lyrics_fixture_test() {
+        let server = fixture_server().await;
+        let lyrics = fixture_genius(&server).get_lyrics(378_195).await.unwrap();
+        assert_eq!(lyrics[0], "[Verse 1]");
+        assert!(lyrics.contains(&"Party girls don't get hurt".to_owned()));
+        assert!(lyrics.contains(&"[Chorus]".to_owned()));
+        assert!(!lyrics.contains(&"You might also like".to_owned()));
     }
 }
 
 const URL: &str = "https://api.genius.com";
+const LYRICS_URL: &str = "https://genius.com";
 
-/// The main hub for interacting with the Genius API
+/// The main hub for interacting with the Genius API, cloning it is cheap as the clones share the configuration and the rate limiter.
+#[derive(Clone)]
 pub struct Genius {
     reqwest: Client,
+    inner: Arc<Inner>,
+}
+
+struct Inner {
     token: String,
+    base_url: String,
+    lyrics_url: String,
+    text_format: Vec<TextFormat>,
+    user_agent: Option<String>,
+    timeout: Option<Duration>,
+    retry: Option<RetryPolicy>,
+    rate_limiter: Option<RateLimiter>,
+    cache: Option<Box<dyn Cache>>,
+    cache_hits: AtomicU64,
+    cache_misses: AtomicU64,
+}
+
+impl Inner {
+    /// Value of the `text_format` query parameter, the default of the client if `text_format` is empty.
+    fn text_format_param(&self, text_format: &[TextFormat]) -> String {
+        if text_format.is_empty() {
+            TextFormat::join(&self.text_format)
+        } else {
+            TextFormat::join(text_format)
+        }
+    }
+
+    fn cache_stats(&self) -> CacheStats {
+        CacheStats {
+            hits: self.cache_hits.load(Ordering::Relaxed),
+            misses: self.cache_misses.load(Ordering::Relaxed),
+        }
+    }
+
+    /// The result of the `endpoint` read from the cache.
+    /// A cached body that can't be read, like a file written halfway, is a miss so it's requested again and replaced.
+    fn cached<T>(&self, endpoint: &Endpoint<'_, T>) -> Option<T> {
+        let (Some(cache), Some(key)) = (&self.cache, &endpoint.cache_key) else {
+            return None;
+        };
+        let value = cache
+            .get(key)
+            .and_then(|body| (endpoint.extract)(&body).ok());
+        let counter = if value.is_some() {
+            &self.cache_hits
+        } else {
+            &self.cache_misses
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+        value
+    }
+
+    /// Store the response `body` of the `endpoint` if it's cached.
+    fn store<T>(&self, endpoint: &Endpoint<'_, T>, body: String) {
+        if let (Some(cache), Some(key)) = (&self.cache, &endpoint.cache_key) {
+            cache.insert(key, body);
+        }
+    }
 }
 
 impl Genius {
     /// Create an API Client at <https://genius.com/developers> and get the token to get basic Genius API access. The token will be level client.
     #[must_use]
     pub fn new(token: String) -> Self {
-        Self {
-            reqwest: Client::new(),
-            token,
-        }
+        GeniusBuilder::new(token).build()
+    }
+
+    /// Configure the client before creating it, see [`GeniusBuilder`].
+    #[must_use]
+    pub fn builder(token: String) -> GeniusBuilder {
+        GeniusBuilder::new(token)
+    }
+
+    /// The text format used when a method is called with an empty `text_format` and when this library requests a song by itself, like in [`Genius::get_lyrics`]. Can be changed with [`GeniusBuilder::text_format`].
+    #[must_use]
+    pub fn text_format(&self) -> &[TextFormat] {
+        &self.inner.text_format
+    }
+
+    /// Number of responses found and not found in the cache set with [`GeniusBuilder::cache`], shared by the clones of this client.
+    #[must_use]
+    pub fn cache_stats(&self) -> CacheStats {
+        self.inner.cache_stats()
     }
 
     /// Search for a song in Genius the result will be [`search::Hit`]
@@ -130,140 +891,583 @@ impl Genius {
     /// Will return [`GeniusError::Unauthorized`] if the token is invalid.
     /// Will return [`GeniusError::ParseError`] if the response is not valid JSON if this occurs you should contact the developer.
     /// Will return [`GeniusError::NotFound`] if the field `hits` is empty in the response if this occurs you should contact the developer.
+    /// Other error statuses are mapped as described in [`GeniusError`].
     pub async fn search(&self, q: &str) -> Result<Vec<Hit>, GeniusError> {
-        let request = self
-            .reqwest
-            .get(format!("{}/search?q={}", URL, q))
-            .bearer_auth(&self.token)
-            .send()
-            .await;
-        let request = match request {
-            Ok(request) => request.json::<Response>().await,
-            Err(e) => return Err(GeniusError::RequestError(e.to_string())),
-        };
-        let res = match request {
-            Ok(res) => res.response.hits,
-            Err(e) => {
-                if let Some(status) = e.status() {
-                    if status.is_client_error() {
-                        return Err(GeniusError::Unauthorized(e.to_string()));
-                    }
-                }
-                return Err(GeniusError::ParseError(e.to_string()));
-            }
-        };
-        match res {
-            Some(res) => Ok(res),
-            None => Err(GeniusError::NotFound("Hits not found in data".to_owned())),
-        }
+        self.search_page(q, None, None).await
+    }
+
+    /// Search for a song in Genius like [`Genius::search`], `per_page` is the number of hits per page and `page` is the page to fetch starting from 1.
+    ///
+    /// # Errors
+    ///
+    /// Same errors as [`Genius::search`].
+    pub async fn search_page(
+        &self,
+        q: &str,
+        per_page: Option<u32>,
+        page: Option<u32>,
+    ) -> Result<Vec<Hit>, GeniusError> {
+        self.call(Endpoint::search(q, per_page, page)).await
     }
 
-    /// Get lyrics with an url of genius song like: <https://genius.com/Sia-chandelier-lyrics>
+    /// Stream all the hits of a search, the pages are requested as the stream is consumed until a page is empty. `per_page` is the number of hits requested per page.
+    ///
+    /// # Errors
+    ///
+    /// Yields the same errors as [`Genius::search`], the stream ends after the first error.
+    pub fn search_stream<'a>(
+        &'a self,
+        q: &'a str,
+        per_page: Option<u32>,
+    ) -> impl Stream<Item = Result<Hit, GeniusError>> + 'a {
+        stream::try_unfold(Some(1), move |page| async move {
+            let Some(page) = page else {
+                return Ok::<_, GeniusError>(None);
+            };
+            let hits = self.search_page(q, per_page, Some(page)).await?;
+            let next_page = if hits.is_empty() {
+                None
+            } else {
+                Some(page + 1)
+            };
+            let hits = stream::iter(hits.into_iter().map(Ok::<Hit, GeniusError>));
+            Ok(Some((hits, next_page)))
+        })
+        .try_flatten()
+    }
+
+    /// Search for songs, lyrics, artists, albums, videos, articles and users in Genius, the result will be [`search::SearchSection`] for each type with its [`search::Hit`].
     ///
     /// # Errors
     ///
     /// Will return [`GeniusError::RequestError`] if the request fails.
+    /// Will return [`GeniusError::Unauthorized`] if the token is invalid.
     /// Will return [`GeniusError::ParseError`] if the response is not valid JSON if this occurs you should contact the developer.
-    /// Will return [`GeniusError::NotFound`] if the field `hits` is empty in the response if this occurs you should contact the developer.
+    /// Will return [`GeniusError::NotFound`] if the field `sections` is empty in the response if this occurs you should contact the developer.
+    /// Other error statuses are mapped as described in [`GeniusError`].
+    pub async fn search_multi(&self, q: &str) -> Result<Vec<SearchSection>, GeniusError> {
+        self.call(Endpoint::search_multi(q)).await
+    }
+
+    /// Find the song that best matches an `artist` and a `title` like the ones of music players, the result will be [`matching::SongMatch`].
+    ///
+    /// Both are compared without casing, punctuation, featured artists and version suffixes like `(Remastered 2011)` to the primary artist, `title` and `title_with_featured` of the search hits.
+    /// Returns `None` if no hit has a confidence of at least [`matching::MIN_CONFIDENCE`].
+    ///
+    /// # Errors
+    ///
+    /// Same errors as [`Genius::search`].
+    pub async fn find_song(
+        &self,
+        artist: &str,
+        title: &str,
+    ) -> Result<Option<SongMatch>, GeniusError> {
+        let hits = self.search(&matching::query(artist, title)).await?;
+        Ok(matching::best_match(artist, title, hits))
+    }
+
+    /// Get lyrics of a song by it's id, to get the lyrics with an url of genius song like: <https://genius.com/Sia-chandelier-lyrics> use [`Genius::resolve_song_id`] first.
+    ///
+    /// The lyrics are scraped from the song page, see [`lyrics::parse_lyrics`].
+    /// > Only with the `lyrics` feature
+    ///
+    /// # Errors
+    ///
+    /// Same errors as [`Genius::get_song`].
+    /// Will return [`GeniusError::RequestError`] if the request of the song page fails.
+    /// Will return [`GeniusError::NotFound`] if the lyrics are not found in the song page.
+    /// #### Examples
+    /// Basic usage:
+    /// ```no_run
+    /// use genius_rs::Genius;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let genius = Genius::new(dotenv::var("TOKEN").unwrap());
+    ///     let response = genius.search("Sia").await.unwrap();
+    ///     let lyrics = genius.get_lyrics(response[0].song().unwrap().id).await.unwrap();
+    ///     for verse in lyrics {
+    ///         println!("{}", verse);
+    ///     }
+    /// }
+    /// ```
+    #[cfg(feature = "lyrics")]
     pub async fn get_lyrics(&self, id: u32) -> Result<Vec<String>, GeniusError> {
-        let request = self
-            .reqwest
-            .get(format!("https://lyrics.altart.tk/api/lyrics/{}", id))
-            .send()
-            .await;
-        let request = match request {
-            Ok(request) => request.json::<Body>().await,
-            Err(e) => return Err(GeniusError::RequestError(e.to_string())),
-        };
-        let plain = match request {
-            Ok(res) => res.plain,
-            Err(e) => {
-                if let Some(status) = e.status() {
-                    if status.is_client_error() {
-                        return Err(GeniusError::Unauthorized(e.to_string()));
-                    }
-                }
-                return Err(GeniusError::ParseError(e.to_string()));
-            }
-        };
-        match plain {
-            Some(text) => Ok(text.split('\n').map(String::from).collect::<Vec<String>>()),
-            None => Err(GeniusError::NotFound("Lyrics not found in data".to_owned())),
-        }
+        let song = self.get_song(id, &self.inner.text_format).await?;
+        let html = self.page(&song.path).await?;
+        endpoint::lyrics_lines(&html)
     }
 
-    /// Get deeper information from a song by it's id, `text_format` is the field for the format of text bodies related to the document. Available text formats are `plain` and `html`
+    /// Get deeper information from a song by it's id, `text_format` is the field for the formats of text bodies related to the document, see [`TextFormat`].
     ///
     /// # Errors
     ///
     /// Will return [`GeniusError::RequestError`] if the request fails.
     /// Will return [`GeniusError::Unauthorized`] if the token is invalid.
     /// Will return [`GeniusError::ParseError`] if the response is not valid JSON if this occurs you should contact the developer.
-    /// Will return [`GeniusError::NotFound`] if the field `hits` is empty in the response if this occurs you should contact the developer.
-    pub async fn get_song(&self, id: u32, text_format: &str) -> Result<Song, GeniusError> {
-        let request = self
-            .reqwest
-            .get(format!("{}/songs/{}?text_format={}", URL, id, text_format))
-            .bearer_auth(&self.token)
-            .send()
-            .await;
-        let request = match request {
-            Ok(request) => request.json::<Response>().await,
-            Err(e) => return Err(GeniusError::RequestError(e.to_string())),
-        };
-        let res = match request {
-            Ok(res) => res.response.song,
-            Err(e) => {
-                if let Some(status) = e.status() {
-                    if status.is_client_error() {
-                        return Err(GeniusError::Unauthorized(e.to_string()));
-                    }
-                }
-                return Err(GeniusError::ParseError(e.to_string()));
-            }
+    /// Will return [`GeniusError::NotFound`] if the song doesn't exist or the field `song` is empty in the response.
+    /// Other error statuses are mapped as described in [`GeniusError`].
+    pub async fn get_song(&self, id: u32, text_format: &[TextFormat]) -> Result<Song, GeniusError> {
+        let text_format = self.inner.text_format_param(text_format);
+        self.call(Endpoint::song(id, text_format)).await
+    }
+
+    /// Get deeper information from a song by an url like <https://genius.com/Sia-chandelier-lyrics>, a [`song::Song::path`] or a [`song::Song::api_path`]. `text_format` is the field for the formats of text bodies related to the document, see [`TextFormat`].
+    ///
+    /// # Errors
+    ///
+    /// Same errors as [`Genius::resolve_song_id`] and [`Genius::get_song`].
+    pub async fn get_song_by_url(
+        &self,
+        url: &str,
+        text_format: &[TextFormat],
+    ) -> Result<Song, GeniusError> {
+        let id = self.resolve_song_id(url).await?;
+        self.get_song(id, text_format).await
+    }
+
+    /// Get the id of a song by an url like <https://genius.com/Sia-chandelier-lyrics>, a [`song::Song::path`] or a [`song::Song::api_path`].
+    ///
+    /// An `api_path` already has the id, the id of a song page is read from the `genius://songs/<id>` app link of the page.
+    ///
+    /// # Errors
+    ///
+    /// Will return [`GeniusError::RequestError`] if the request of the song page fails.
+    /// Will return [`GeniusError::NotFound`] if the url isn't from a genius song, the song page doesn't exist or it has no app link.
+    pub async fn resolve_song_id(&self, url: &str) -> Result<u32, GeniusError> {
+        let path = match SongPath::parse(url) {
+            Some(SongPath::Id(id)) => return Ok(id),
+            Some(SongPath::Page(path)) => path,
+            None => return Err(GeniusError::NotFound(format!("{url} is not a genius song"))),
         };
-        match res {
-            Some(res) => Ok(res),
-            None => Err(GeniusError::NotFound("Song not found in data".to_owned())),
-        }
+        let html = self.page(&path).await?;
+        endpoint::page_song_id(&html, &path)
     }
-    /// Get deeper information from a album by it's id, `text_format` is the field for the format of text bodies related to the document. Available text formats are `plain` and `html`
+
+    /// Get deeper information from a album by it's id, `text_format` is the field for the formats of text bodies related to the document, see [`TextFormat`].
     ///
     /// # Errors
     ///
     /// Will return [`GeniusError::RequestError`] if the request fails.
     /// Will return [`GeniusError::Unauthorized`] if the token is invalid.
     /// Will return [`GeniusError::ParseError`] if the response is not valid JSON if this occurs you should contact the developer.
-    /// Will return [`GeniusError::NotFound`] if the field `hits` is empty in the response if this occurs you should contact the developer.
-    pub async fn get_album(&self, id: u32, text_format: &str) -> Result<Album, GeniusError> {
+    /// Will return [`GeniusError::NotFound`] if the album doesn't exist or the field `album` is empty in the response.
+    /// Other error statuses are mapped as described in [`GeniusError`].
+    pub async fn get_album(
+        &self,
+        id: u32,
+        text_format: &[TextFormat],
+    ) -> Result<Album, GeniusError> {
+        let text_format = self.inner.text_format_param(text_format);
+        self.call(Endpoint::album(id, text_format)).await
+    }
+
+    /// Get deeper information from an artist by it's id, `text_format` is the field for the formats of text bodies related to the document, see [`TextFormat`].
+    ///
+    /// # Errors
+    ///
+    /// Will return [`GeniusError::RequestError`] if the request fails.
+    /// Will return [`GeniusError::Unauthorized`] if the token is invalid.
+    /// Will return [`GeniusError::ParseError`] if the response is not valid JSON if this occurs you should contact the developer.
+    /// Will return [`GeniusError::NotFound`] if the artist doesn't exist or the field `artist` is empty in the response.
+    /// Other error statuses are mapped as described in [`GeniusError`].
+    pub async fn get_artist(
+        &self,
+        id: u32,
+        text_format: &[TextFormat],
+    ) -> Result<Artist, GeniusError> {
+        let text_format = self.inner.text_format_param(text_format);
+        self.call(Endpoint::artist(id, text_format)).await
+    }
+
+    /// Get the songs of an artist by it's id the result will be [`song::Song`], `sort` is the order of the songs, `per_page` is the number of songs per page (Genius max is 50) and `page` is the page to fetch starting from 1.
+    ///
+    /// # Errors
+    ///
+    /// Will return [`GeniusError::RequestError`] if the request fails.
+    /// Will return [`GeniusError::Unauthorized`] if the token is invalid.
+    /// Will return [`GeniusError::ParseError`] if the response is not valid JSON if this occurs you should contact the developer.
+    /// Will return [`GeniusError::NotFound`] if the artist doesn't exist or the field `songs` is empty in the response.
+    /// Other error statuses are mapped as described in [`GeniusError`].
+    pub async fn artist_songs(
+        &self,
+        artist_id: u32,
+        sort: SongSort,
+        per_page: Option<u32>,
+        page: Option<u32>,
+    ) -> Result<Vec<Song>, GeniusError> {
+        let endpoint = Endpoint::artist_songs(artist_id, sort, per_page, page);
+        let (songs, _) = self.call(endpoint).await?;
+        Ok(songs)
+    }
+
+    /// Stream all the songs of an artist by it's id, the pages are requested following `next_page` as the stream is consumed. `per_page` is the number of songs requested per page (Genius max is 50).
+    ///
+    /// # Errors
+    ///
+    /// Yields the same errors as [`Genius::artist_songs`], the stream ends after the first error.
+    pub fn artist_songs_stream(
+        &self,
+        artist_id: u32,
+        sort: SongSort,
+        per_page: Option<u32>,
+    ) -> impl Stream<Item = Result<Song, GeniusError>> + '_ {
+        stream::try_unfold(Some(1), move |page| async move {
+            let Some(page) = page else {
+                return Ok::<_, GeniusError>(None);
+            };
+            let endpoint = Endpoint::artist_songs(artist_id, sort, per_page, Some(page));
+            let (songs, next_page) = self.call(endpoint).await?;
+            let songs = stream::iter(songs.into_iter().map(Ok::<Song, GeniusError>));
+            Ok(Some((songs, next_page)))
+        })
+        .try_flatten()
+    }
+
+    /// Get the annotated fragments of a song, a web page or created by an user, the result will be [`annotation::Referent`] with its annotations.
+    ///
+    /// `document` is the song or the web page of the referents, see [`annotation::ReferentsFor`]. `created_by_id` is the id of the user that created the referents.
+    /// `text_format` is the field for the formats of text bodies related to the document, see [`TextFormat`].
+    /// `per_page` is the number of referents per page and `page` is the page to fetch starting from 1.
+    ///
+    /// # Errors
+    ///
+    /// Will return [`GeniusError::InvalidArguments`] if neither `document` nor `created_by_id` is given.
+    /// Will return [`GeniusError::RequestError`] if the request fails.
+    /// Will return [`GeniusError::Unauthorized`] if the token is invalid.
+    /// Will return [`GeniusError::ParseError`] if the response is not valid JSON if this occurs you should contact the developer.
+    /// Will return [`GeniusError::NotFound`] if the field `referents` is empty in the response if this occurs you should contact the developer.
+    /// Other error statuses are mapped as described in [`GeniusError`].
+    pub async fn get_referents(
+        &self,
+        document: Option<ReferentsFor>,
+        created_by_id: Option<u32>,
+        text_format: &[TextFormat],
+        per_page: Option<u32>,
+        page: Option<u32>,
+    ) -> Result<Vec<Referent>, GeniusError> {
+        let text_format = self.inner.text_format_param(text_format);
+        let endpoint = Endpoint::referents(document, created_by_id, text_format, per_page, page)?;
+        self.call(endpoint).await
+    }
+
+    /// Get an annotation by it's id and the [`annotation::Referent`] it's attached to, `text_format` is the field for the formats of text bodies related to the document, see [`TextFormat`].
+    ///
+    /// # Errors
+    ///
+    /// Will return [`GeniusError::RequestError`] if the request fails.
+    /// Will return [`GeniusError::Unauthorized`] if the token is invalid.
+    /// Will return [`GeniusError::ParseError`] if the response is not valid JSON if this occurs you should contact the developer.
+    /// Will return [`GeniusError::NotFound`] if the annotation doesn't exist or the field `annotation` or `referent` is empty in the response.
+    /// Other error statuses are mapped as described in [`GeniusError`].
+    pub async fn get_annotation(
+        &self,
+        id: u32,
+        text_format: &[TextFormat],
+    ) -> Result<(Annotation, Referent), GeniusError> {
+        let text_format = self.inner.text_format_param(text_format);
+        self.call(Endpoint::annotation(id, text_format)).await
+    }
+
+    /// Create an annotation in a web page, the result will be the created [`annotation::Annotation`]. `text_format` is the field for the formats of text bodies related to the document, see [`TextFormat`].
+    ///
+    /// > Requires a token with the `create_annotation` scope.
+    ///
+    /// # Errors
+    ///
+    /// Will return [`GeniusError::RequestError`] if the request fails.
+    /// Will return [`GeniusError::Unauthorized`] if the token is invalid.
+    /// Will return [`GeniusError::Forbidden`] if the token doesn't have the `create_annotation` scope.
+    /// Will return [`GeniusError::UnprocessableEntity`] if the annotation is invalid, like a fragment that isn't in the page.
+    /// Will return [`GeniusError::ParseError`] if the response is not valid JSON if this occurs you should contact the developer.
+    /// Will return [`GeniusError::NotFound`] if the field `annotation` is empty in the response if this occurs you should contact the developer.
+    /// Other error statuses are mapped as described in [`GeniusError`].
+    pub async fn create_annotation(
+        &self,
+        annotation: &AnnotationRequest,
+        text_format: &[TextFormat],
+    ) -> Result<Annotation, GeniusError> {
+        let text_format = self.inner.text_format_param(text_format);
+        self.call(Endpoint::create_annotation(annotation, text_format))
+            .await
+    }
+
+    /// Update an annotation by it's id, the result will be the updated [`annotation::Annotation`]. `text_format` is the field for the formats of text bodies related to the document, see [`TextFormat`].
+    ///
+    /// > Requires a token with the `manage_annotation` scope.
+    ///
+    /// # Errors
+    ///
+    /// Will return [`GeniusError::RequestError`] if the request fails.
+    /// Will return [`GeniusError::Unauthorized`] if the token is invalid.
+    /// Will return [`GeniusError::Forbidden`] if the token doesn't have the `manage_annotation` scope.
+    /// Will return [`GeniusError::UnprocessableEntity`] if the annotation is invalid.
+    /// Will return [`GeniusError::ParseError`] if the response is not valid JSON if this occurs you should contact the developer.
+    /// Will return [`GeniusError::NotFound`] if the annotation doesn't exist or the field `annotation` is empty in the response.
+    /// Other error statuses are mapped as described in [`GeniusError`].
+    pub async fn update_annotation(
+        &self,
+        id: u32,
+        annotation: &AnnotationRequest,
+        text_format: &[TextFormat],
+    ) -> Result<Annotation, GeniusError> {
+        let text_format = self.inner.text_format_param(text_format);
+        self.call(Endpoint::update_annotation(id, annotation, text_format))
+            .await
+    }
+
+    /// Delete an annotation by it's id.
+    ///
+    /// > Requires a token with the `manage_annotation` scope.
+    ///
+    /// # Errors
+    ///
+    /// Will return [`GeniusError::RequestError`] if the request fails.
+    /// Will return [`GeniusError::Unauthorized`] if the token is invalid.
+    /// Will return [`GeniusError::Forbidden`] if the token doesn't have the `manage_annotation` scope.
+    /// Will return [`GeniusError::NotFound`] if the annotation doesn't exist.
+    /// Other error statuses are mapped as described in [`GeniusError`].
+    pub async fn delete_annotation(&self, id: u32) -> Result<(), GeniusError> {
+        self.call(Endpoint::delete_annotation(id)).await
+    }
+
+    /// Upvote an annotation by it's id, the result will be the refreshed [`annotation::Annotation`]. `text_format` is the field for the formats of text bodies related to the document, see [`TextFormat`].
+    ///
+    /// > Requires a token with the `vote` scope.
+    ///
+    /// # Errors
+    ///
+    /// Will return [`GeniusError::RequestError`] if the request fails.
+    /// Will return [`GeniusError::Unauthorized`] if the token is invalid.
+    /// Will return [`GeniusError::Forbidden`] if the token doesn't have the `vote` scope.
+    /// Will return [`GeniusError::ParseError`] if the response is not valid JSON if this occurs you should contact the developer.
+    /// Will return [`GeniusError::NotFound`] if the annotation doesn't exist or the field `annotation` is empty in the response.
+    /// Other error statuses are mapped as described in [`GeniusError`].
+    pub async fn upvote_annotation(
+        &self,
+        id: u32,
+        text_format: &[TextFormat],
+    ) -> Result<Annotation, GeniusError> {
+        self.vote_annotation(id, "upvote", text_format).await
+    }
+
+    /// Downvote an annotation by it's id, the result will be the refreshed [`annotation::Annotation`]. `text_format` is the field for the formats of text bodies related to the document, see [`TextFormat`].
+    ///
+    /// > Requires a token with the `vote` scope.
+    ///
+    /// # Errors
+    ///
+    /// Same errors as [`Genius::upvote_annotation`].
+    pub async fn downvote_annotation(
+        &self,
+        id: u32,
+        text_format: &[TextFormat],
+    ) -> Result<Annotation, GeniusError> {
+        self.vote_annotation(id, "downvote", text_format).await
+    }
+
+    /// Remove the vote of an annotation by it's id, the result will be the refreshed [`annotation::Annotation`]. `text_format` is the field for the formats of text bodies related to the document, see [`TextFormat`].
+    ///
+    /// > Requires a token with the `vote` scope.
+    ///
+    /// # Errors
+    ///
+    /// Same errors as [`Genius::upvote_annotation`].
+    pub async fn unvote_annotation(
+        &self,
+        id: u32,
+        text_format: &[TextFormat],
+    ) -> Result<Annotation, GeniusError> {
+        self.vote_annotation(id, "unvote", text_format).await
+    }
+
+    /// Get the account of the owner of the token, the result will be [`user::Account`].
+    ///
+    /// > Requires a token with the `me` scope.
+    ///
+    /// # Errors
+    ///
+    /// Will return [`GeniusError::RequestError`] if the request fails.
+    /// Will return [`GeniusError::Unauthorized`] if the token is invalid.
+    /// Will return [`GeniusError::Unauthorized`] if the token doesn't have the `me` scope, unlike other endpoints that return [`GeniusError::Forbidden`] for a missing scope.
+    /// Will return [`GeniusError::ParseError`] if the response is not valid JSON if this occurs you should contact the developer.
+    /// Will return [`GeniusError::NotFound`] if the field `user` is empty in the response if this occurs you should contact the developer.
+    /// Other error statuses are mapped as described in [`GeniusError`].
+    pub async fn get_account(&self) -> Result<Account, GeniusError> {
+        self.call(Endpoint::account()).await
+    }
+
+    /// Find a web page by its urls, at least one of them must be given, the result will be [`web_page::WebPage`].
+    ///
+    /// `raw_annotatable_url` is the url as it would appear in the browser, `canonical_url` is the url from `<link rel="canonical">` and `og_url` is the url from `<meta property="og:url">`.
+    ///
+    /// # Errors
+    ///
+    /// Will return [`GeniusError::InvalidArguments`] if no url is given.
+    /// Will return [`GeniusError::RequestError`] if the request fails.
+    /// Will return [`GeniusError::Unauthorized`] if the token is invalid.
+    /// Will return [`GeniusError::ParseError`] if the response is not valid JSON if this occurs you should contact the developer.
+    /// Will return [`GeniusError::NotFound`] if the web page isn't known by Genius or the field `web_page` is empty in the response.
+    /// Other error statuses are mapped as described in [`GeniusError`].
+    pub async fn lookup_web_page(
+        &self,
+        raw_annotatable_url: Option<&str>,
+        canonical_url: Option<&str>,
+        og_url: Option<&str>,
+    ) -> Result<WebPage, GeniusError> {
+        let endpoint = Endpoint::lookup_web_page(raw_annotatable_url, canonical_url, og_url)?;
+        self.call(endpoint).await
+    }
+
+    async fn vote_annotation(
+        &self,
+        id: u32,
+        vote: &str,
+        text_format: &[TextFormat],
+    ) -> Result<Annotation, GeniusError> {
+        let text_format = self.inner.text_format_param(text_format);
+        self.call(Endpoint::vote_annotation(id, vote, text_format))
+            .await
+    }
+
+    /// Send the request of the `endpoint` and read its result, the cached endpoints are read from the cache first and stored after a successful response.
+    async fn call<T>(&self, endpoint: Endpoint<'_, T>) -> Result<T, GeniusError> {
+        if let Some(value) = self.inner.cached(&endpoint) {
+            return Ok(value);
+        }
+        let mut request = self
+            .request(endpoint.method.clone(), &endpoint.path)
+            .query(&endpoint.query);
+        if let Some(body) = endpoint.body {
+            request = request.json(body);
+        }
+        let body = self.send(request).await.map_err(endpoint.map_error)?;
+        let value = (endpoint.extract)(&body)?;
+        self.inner.store(&endpoint, body);
+        Ok(value)
+    }
+
+    /// Request a page of the Genius site with the `path` like `/Sia-chandelier-lyrics`.
+    async fn page(&self, path: &str) -> Result<String, GeniusError> {
+        let request =
+            self.with_options(self.reqwest.get(format!("{}{path}", self.inner.lyrics_url)));
+        self.send(request).await
+    }
+
+    fn request(&self, method: Method, path: &str) -> RequestBuilder {
         let request = self
             .reqwest
-            .get(format!("{}/albums/{}?text_format={}", URL, id, text_format))
-            .bearer_auth(&self.token)
-            .send()
-            .await;
-        let request = match request {
-            Ok(request) => request.json::<Response>().await,
-            Err(e) => return Err(GeniusError::RequestError(e.to_string())),
-        };
-        let res = match request {
-            Ok(res) => res.response.album,
-            Err(e) => return Err(GeniusError::ParseError(e.to_string())),
-        };
-        match res {
-            Some(res) => Ok(res),
-            None => Err(GeniusError::NotFound("Album not found in data".to_owned())),
+            .request(method, format!("{}{path}", self.inner.base_url))
+            .bearer_auth(&self.inner.token);
+        self.with_options(request)
+    }
+
+    fn with_options(&self, mut request: RequestBuilder) -> RequestBuilder {
+        if let Some(user_agent) = &self.inner.user_agent {
+            request = request.header(USER_AGENT, user_agent);
         }
+        if let Some(timeout) = self.inner.timeout {
+            request = request.timeout(timeout);
+        }
+        request
+    }
+
+    /// Send the request and read the body, error statuses are mapped with [`GeniusError::from_status`].
+    /// The request is sent again while it fails with a transient error and the [`RetryPolicy`] allows it.
+    async fn send(&self, request: RequestBuilder) -> Result<String, GeniusError> {
+        let request = request.build()?;
+        let mut attempt = 1;
+        loop {
+            let retry = self
+                .inner
+                .retry
+                .as_ref()
+                .filter(|retry| retry.allows(request.method(), attempt));
+            let (Some(retry), Some(current)) = (retry, request.try_clone()) else {
+                return self.send_once(request).await.map_err(|(error, _)| error);
+            };
+            match self.send_once(current).await {
+                Err((error, retry_after)) if RetryPolicy::is_transient(&error) => {
+                    let Some(delay) = retry.delay(attempt, retry_after) else {
+                        return Err(error);
+                    };
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                }
+                result => return result.map_err(|(error, _)| error),
+            }
+        }
+    }
+
+    /// Send the request once, the error comes with the `Retry-After` header of the response.
+    async fn send_once(&self, request: Request) -> Result<String, (GeniusError, Option<Duration>)> {
+        if let Some(rate_limiter) = &self.inner.rate_limiter {
+            rate_limiter.acquire().await;
+        }
+        let response = self
+            .reqwest
+            .execute(request)
+            .await
+            .map_err(|e| (GeniusError::RequestError(e), None))?;
+        if let Some(source) = response.error_for_status_ref().err() {
+            let retry_after = GeniusError::retry_after(response.headers());
+            let status = response.status();
+            let body = response.text().await.unwrap_or_default();
+            let error = GeniusError::from_status(status, &body, retry_after)
+                .unwrap_or(GeniusError::RequestError(source));
+            return Err((error, retry_after));
+        }
+        response
+            .text()
+            .await
+            .map_err(|e| (GeniusError::RequestError(e), None))
     }
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct Body {
+    /// > Only with [`TextFormat::Plain`]
     pub plain: Option<String>,
+    /// > Only with [`TextFormat::Html`]
     pub html: Option<String>,
+    /// > Only with [`TextFormat::Dom`]
+    pub dom: Option<DomNode>,
 }
 
-#[derive(Deserialize, Debug)]
+/// Format of the text bodies like [`Body`], many formats can be requested at once like `&[TextFormat::Plain, TextFormat::Html]`.
+///
+/// The methods requested with an empty slice `&[]` use the default format of the client, see [`Genius::text_format`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextFormat {
+    /// Plain text without markup.
+    Plain,
+    /// HTML markup.
+    Html,
+    /// JSON tree of the HTML, see [`dom::DomNode`].
+    Dom,
+}
+
+impl TextFormat {
+    /// Value of the format in the `text_format` query parameter.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Plain => "plain",
+            Self::Html => "html",
+            Self::Dom => "dom",
+        }
+    }
+
+    fn join(text_format: &[Self]) -> String {
+        text_format
+            .iter()
+            .map(|format| format.as_str())
+            .collect::<Vec<&str>>()
+            .join(",")
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 pub struct Date {
     pub year: Option<u32>,
     pub month: Option<u32>,
@@ -279,5 +1483,14 @@ struct Response {
 struct BlobResponse {
     song: Option<Song>,
     hits: Option<Vec<Hit>>,
+    sections: Option<Vec<SearchSection>>,
     album: Option<Album>,
+    artist: Option<Artist>,
+    songs: Option<Vec<Song>>,
+    next_page: Option<u32>,
+    referents: Option<Vec<Referent>>,
+    annotation: Option<Annotation>,
+    referent: Option<Referent>,
+    user: Option<Account>,
+    web_page: Option<WebPage>,
 }
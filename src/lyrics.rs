@@ -0,0 +1,486 @@
+#[cfg(feature = "lyrics")]
+use scraper::{ElementRef, Html, Node, Selector};
+
+use crate::annotation::Referent;
+use crate::Body;
+
+/// Lyrics of a song split in the sections marked by headers like `[Chorus]` or `[Verse 2: Artist]`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Lyrics {
+    pub sections: Vec<Section>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Section {
+    /// Kind of the section from the header.
+    pub kind: SectionKind,
+    /// Number of the section like the `2` in `[Verse 2]`.
+    pub ordinal: Option<u32>,
+    /// Artists who perform the section like `Artist` in `[Verse 2: Artist]`, empty if the header has no artists.
+    ///
+    /// The artists are split on `,` and ` & ` like Genius separates them in headers, so `[Chorus: Drake & Rihanna]` is `["Drake", "Rihanna"]`.
+    /// Names with a ` & ` like `Simon & Garfunkel` are split too, [`Section::performers_raw`] keeps them as written.
+    pub performers: Vec<String>,
+    /// Artists of the header as written like `Drake & Rihanna` in `[Chorus: Drake & Rihanna]`, `None` if the header has no artists.
+    pub performers_raw: Option<String>,
+    /// Lines of the section without the header and the empty lines.
+    pub lines: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SectionKind {
+    Intro,
+    Verse,
+    PreChorus,
+    Chorus,
+    PostChorus,
+    Hook,
+    Refrain,
+    Bridge,
+    Interlude,
+    Breakdown,
+    Instrumental,
+    Outro,
+    /// Header that is not a known kind, like `[Skit]`.
+    Other(String),
+    /// Lines before the first header.
+    Untitled,
+}
+
+impl SectionKind {
+    fn from_label(label: &str) -> Self {
+        match label.to_lowercase().replace(['-', ' '], "").as_str() {
+            "intro" => Self::Intro,
+            "verse" => Self::Verse,
+            "prechorus" => Self::PreChorus,
+            "chorus" => Self::Chorus,
+            "postchorus" => Self::PostChorus,
+            "hook" => Self::Hook,
+            "refrain" => Self::Refrain,
+            "bridge" => Self::Bridge,
+            "interlude" => Self::Interlude,
+            "breakdown" => Self::Breakdown,
+            "instrumental" => Self::Instrumental,
+            "outro" => Self::Outro,
+            _ => Self::Other(label.to_owned()),
+        }
+    }
+}
+
+impl Lyrics {
+    /// Parse the lyrics in plain text, each header like `[Verse 2: Artist]` starts a new [`Section`].
+    /// #### Examples
+    /// Basic usage:
+    /// ```
+    /// use genius_rs::lyrics::{Lyrics, SectionKind};
+    ///
+    /// let lyrics = Lyrics::parse("[Chorus: Sia]\nI'm gonna swing from the chandelier");
+    /// assert_eq!(lyrics.sections[0].kind, SectionKind::Chorus);
+    /// ```
+    #[must_use]
+    pub fn parse(text: &str) -> Self {
+        let mut sections: Vec<Section> = vec![];
+        for line in text.lines().map(str::trim) {
+            if line.is_empty() {
+                continue;
+            }
+            if let Some(header) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+                sections.push(Section::from_header(header));
+                continue;
+            }
+            match sections.last_mut() {
+                Some(section) => section.lines.push(line.to_owned()),
+                None => sections.push(Section {
+                    kind: SectionKind::Untitled,
+                    ordinal: None,
+                    performers: vec![],
+                    performers_raw: None,
+                    lines: vec![line.to_owned()],
+                }),
+            }
+        }
+        Self { sections }
+    }
+
+    /// Parse the lyrics from a [`Body`], the `plain` text is used when present otherwise the `html` is transformed in plain text.
+    /// > The `html` is only used with the `lyrics` feature
+    ///
+    /// Returns `None` if the body has no text.
+    #[must_use]
+    pub fn from_body(body: &Body) -> Option<Self> {
+        if let Some(plain) = &body.plain {
+            return Some(Self::parse(plain));
+        }
+        #[cfg(feature = "lyrics")]
+        if let Some(html) = &body.html {
+            return Some(Self::parse(&html_to_text(html)));
+        }
+        None
+    }
+}
+
+/// A line of the [`Lyrics`] with the spans annotated by referents, see [`Lyrics::annotate`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AnnotatedLine {
+    /// Index of the section in [`Lyrics::sections`].
+    pub section: usize,
+    /// Index of the line in [`Section::lines`].
+    pub line: usize,
+    /// Annotated spans of the line ordered by `start`, empty if the line has no annotations.
+    pub spans: Vec<AnnotatedSpan>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AnnotatedSpan {
+    /// Character offset where the span starts.
+    pub start: usize,
+    /// Character offset where the span ends, exclusive.
+    pub end: usize,
+    /// Id of the [`Referent`] that annotates the span.
+    pub referent_id: u32,
+}
+
+impl Lyrics {
+    /// Find where the `fragment` of each referent is in the lyrics, the result has every line of the lyrics in order with its annotated spans.
+    ///
+    /// A fragment that repeats in the lyrics, like in a chorus, annotates every occurrence. Fragments can span many lines. A fragment inside a longer word, like `chandelier` in `chandeliers`, isn't annotated.
+    #[must_use]
+    pub fn annotate(&self, referents: &[Referent]) -> Vec<AnnotatedLine> {
+        let lines: Vec<(usize, usize, &str)> = self
+            .sections
+            .iter()
+            .enumerate()
+            .flat_map(|(s, section)| {
+                section
+                    .lines
+                    .iter()
+                    .enumerate()
+                    .map(move |(l, line)| (s, l, line.as_str()))
+            })
+            .collect();
+        let mut annotated: Vec<AnnotatedLine> = lines
+            .iter()
+            .map(|&(section, line, _)| AnnotatedLine {
+                section,
+                line,
+                spans: vec![],
+            })
+            .collect();
+        for referent in referents {
+            let fragment: Vec<&str> = referent
+                .fragment
+                .lines()
+                .map(str::trim)
+                .filter(|l| !l.is_empty() && !is_header(l))
+                .collect();
+            match fragment.as_slice() {
+                [] => {}
+                [single] => {
+                    for (i, &(_, _, line)) in lines.iter().enumerate() {
+                        for (byte, _) in line.match_indices(single) {
+                            if !is_word(line, byte, byte + single.len()) {
+                                continue;
+                            }
+                            let start = line[..byte].chars().count();
+                            annotated[i].spans.push(AnnotatedSpan {
+                                start,
+                                end: start + single.chars().count(),
+                                referent_id: referent.id,
+                            });
+                        }
+                    }
+                }
+                [first, middle @ .., last] => {
+                    let len = fragment.len();
+                    for i in 0..lines.len().saturating_sub(len - 1) {
+                        let window = &lines[i..i + len];
+                        let (first_line, last_line) = (window[0].2, window[len - 1].2);
+                        let matches = first_line.ends_with(first)
+                            && is_word(
+                                first_line,
+                                first_line.len() - first.len(),
+                                first_line.len(),
+                            )
+                            && last_line.starts_with(last)
+                            && is_word(last_line, 0, last.len())
+                            && middle
+                                .iter()
+                                .zip(&window[1..len - 1])
+                                .all(|(m, l)| *m == l.2);
+                        if !matches {
+                            continue;
+                        }
+                        for (k, &(_, _, line)) in window.iter().enumerate() {
+                            let count = line.chars().count();
+                            let (start, end) = if k == 0 {
+                                (count - first.chars().count(), count)
+                            } else if k == len - 1 {
+                                (0, last.chars().count())
+                            } else {
+                                (0, count)
+                            };
+                            annotated[i + k].spans.push(AnnotatedSpan {
+                                start,
+                                end,
+                                referent_id: referent.id,
+                            });
+                        }
+                    }
+                }
+            }
+        }
+        for line in &mut annotated {
+            line.spans.sort_by_key(|span| span.start);
+        }
+        annotated
+    }
+}
+
+impl Section {
+    fn from_header(header: &str) -> Self {
+        let (label, performers) = match header.split_once(':') {
+            Some((label, performers)) => (label.trim(), performers),
+            None => (header.trim(), ""),
+        };
+        let performers_raw = Some(performers.trim())
+            .filter(|p| !p.is_empty())
+            .map(String::from);
+        let performers = performers
+            .split(',')
+            .flat_map(|performer| performer.split(" & "))
+            .map(str::trim)
+            .filter(|p| !p.is_empty())
+            .map(String::from)
+            .collect();
+        let (label, ordinal) = match label.rsplit_once(' ') {
+            Some((name, number)) => number
+                .parse()
+                .map_or((label, None), |ordinal| (name.trim(), Some(ordinal))),
+            None => (label, None),
+        };
+        Self {
+            kind: SectionKind::from_label(label),
+            ordinal,
+            performers,
+            performers_raw,
+            lines: vec![],
+        }
+    }
+}
+
+/// If the text between the bytes `start` and `end` of the `line` isn't part of a longer word, like `chandelier` in `chandeliers`.
+fn is_word(line: &str, start: usize, end: usize) -> bool {
+    let text = &line[start..end];
+    let joined = |outside: Option<char>, inside: Option<char>| {
+        outside.is_some_and(char::is_alphanumeric) && inside.is_some_and(char::is_alphanumeric)
+    };
+    !joined(line[..start].chars().next_back(), text.chars().next())
+        && !joined(line[end..].chars().next(), text.chars().next_back())
+}
+
+fn is_header(line: &str) -> bool {
+    line.starts_with('[') && line.ends_with(']')
+}
+
+/// Extract the lyrics in plain text from the HTML of a song page like <https://genius.com/Sia-chandelier-lyrics>.
+///
+/// The lyrics are in the `data-lyrics-container` elements, `<br>` are transformed in new lines and the text of annotation links, italic and bold is kept.
+/// Returns `None` if the page has no lyrics.
+/// > Only with the `lyrics` feature
+#[cfg(feature = "lyrics")]
+#[must_use]
+pub fn parse_lyrics(html: &str) -> Option<String> {
+    let document = Html::parse_document(html);
+    let containers: Vec<String> = document
+        .select(&container_selector())
+        .map(|container| {
+            let mut text = String::new();
+            push_text(container, &mut text);
+            text
+        })
+        .collect();
+    if containers.is_empty() {
+        return None;
+    }
+    Some(containers.join("\n"))
+}
+
+#[cfg(feature = "lyrics")]
+fn html_to_text(html: &str) -> String {
+    let fragment = Html::parse_fragment(html);
+    let mut text = String::new();
+    push_text(fragment.root_element(), &mut text);
+    text
+}
+
+#[cfg(feature = "lyrics")]
+fn container_selector() -> Selector {
+    Selector::parse(r#"div[data-lyrics-container="true"]"#)
+        .expect("Can't parse lyrics container selector.")
+}
+
+#[cfg(feature = "lyrics")]
+fn push_text(element: ElementRef, text: &mut String) {
+    for child in element.children() {
+        match child.value() {
+            Node::Text(t) => text.push_str(t),
+            Node::Element(e) if e.name() == "br" => text.push('\n'),
+            Node::Element(e) if e.attr("data-exclude-from-selection").is_some() => {}
+            Node::Element(_) => {
+                if let Some(child) = ElementRef::wrap(child) {
+                    push_text(child, text);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::lyrics::{Lyrics, SectionKind};
+
+    #[cfg(feature = "lyrics")]
+    #[test]
+    fn parse_lyrics_test() {
+        use crate::lyrics::parse_lyrics;
+
+        let html = r#"
+            <div data-lyrics-container="true">[Verse 1]<br><a href="/1"><span>Party girls don't get hurt</span></a><br><i>Can't feel anything</i>, <b>when will I learn?</b></div>
+            <div data-lyrics-container="true"><div data-exclude-from-selection="true">Ad</div>[Chorus]<br>I'm gonna swing from the chandelier</div>
+        "#;
+        assert_eq!(
+            parse_lyrics(html),
+            Some(
+                "[Verse 1]\nParty girls don't get hurt\nCan't feel anything, when will I learn?\n[Chorus]\nI'm gonna swing from the chandelier"
+                    .to_owned()
+            )
+        );
+        assert_eq!(parse_lyrics("<div>No lyrics here</div>"), None);
+    }
+
+    #[test]
+    fn lyrics_sections_test() {
+        let lyrics = Lyrics::parse(
+            "Intro line\n\n[Verse 2: Drake & Rihanna, Future]\nFirst line\nSecond line\n\n[Pre-Chorus]\nThird line\n[Skit]\n",
+        );
+        let kinds: Vec<&SectionKind> = lyrics.sections.iter().map(|s| &s.kind).collect();
+        assert_eq!(
+            kinds,
+            [
+                &SectionKind::Untitled,
+                &SectionKind::Verse,
+                &SectionKind::PreChorus,
+                &SectionKind::Other("Skit".to_owned())
+            ]
+        );
+        let verse = &lyrics.sections[1];
+        assert_eq!(verse.ordinal, Some(2));
+        assert_eq!(verse.performers, ["Drake", "Rihanna", "Future"]);
+        assert_eq!(verse.lines, ["First line", "Second line"]);
+        assert!(lyrics.sections[3].lines.is_empty());
+        assert_eq!(
+            verse.performers_raw.as_deref(),
+            Some("Drake & Rihanna, Future")
+        );
+        assert_eq!(lyrics.sections[2].performers_raw, None);
+        let duo =
+            Lyrics::parse("[Chorus: Drake & Rihanna]\nHello\n[Verse: Simon & Garfunkel, Future]\n");
+        assert_eq!(duo.sections[0].performers, ["Drake", "Rihanna"]);
+        assert_eq!(duo.sections[1].performers, ["Simon", "Garfunkel", "Future"]);
+        assert_eq!(
+            duo.sections[1].performers_raw.as_deref(),
+            Some("Simon & Garfunkel, Future")
+        );
+    }
+
+    #[test]
+    fn lyrics_annotate_test() {
+        use crate::annotation::Referent;
+        use crate::lyrics::AnnotatedSpan;
+
+        let referent = |id: u32, fragment: &str| -> Referent {
+            serde_json::from_value(serde_json::json!({
+                "_type": "referent",
+                "annotator_id": 1,
+                "annotator_login": "login",
+                "api_path": format!("/referents/{id}"),
+                "classification": "accepted",
+                "fragment": fragment,
+                "id": id,
+                "is_description": false,
+                "path": format!("/{id}/Sia-chandelier/"),
+                "range": { "content": fragment },
+                "song_id": 378_195,
+                "url": format!("https://genius.com/{id}/Sia-chandelier/"),
+                "annotations": []
+            }))
+            .unwrap()
+        };
+        let lyrics = Lyrics::parse(
+            "[Verse 1]\nParty girls don't get hurt\nCan't feel anything\n[Chorus]\nI'm gonna swing from the chandelier\n[Chorus]\nI'm gonna swing from the chandelier",
+        );
+        let annotated = lyrics.annotate(&[
+            referent(1, "girls don't get hurt\nCan't feel"),
+            referent(2, "chandelier"),
+        ]);
+        assert_eq!(annotated.len(), 4);
+        assert_eq!(
+            annotated[0].spans,
+            [AnnotatedSpan {
+                start: 6,
+                end: 26,
+                referent_id: 1
+            }]
+        );
+        assert_eq!(
+            annotated[1].spans,
+            [AnnotatedSpan {
+                start: 0,
+                end: 10,
+                referent_id: 1
+            }]
+        );
+        assert_eq!((annotated[3].section, annotated[3].line), (2, 0));
+        assert_eq!(
+            annotated[3].spans,
+            [AnnotatedSpan {
+                start: 25,
+                end: 35,
+                referent_id: 2
+            }]
+        );
+        let annotated = Lyrics::parse("Crystal chandeliers\nFrom the chandelier")
+            .annotate(&[referent(2, "chandelier")]);
+        assert!(annotated[0].spans.is_empty());
+        assert_eq!(
+            annotated[1].spans,
+            [AnnotatedSpan {
+                start: 9,
+                end: 19,
+                referent_id: 2
+            }]
+        );
+    }
+
+    #[cfg(feature = "lyrics")]
+    #[test]
+    fn lyrics_from_body_test() {
+        use crate::Body;
+
+        let body = Body {
+            plain: None,
+            dom: None,
+            html: Some(
+                "<p>[Chorus]<br><a href=\"/1\">I'm gonna swing</a><br>From the chandelier</p>"
+                    .to_owned(),
+            ),
+        };
+        let lyrics = Lyrics::from_body(&body).unwrap();
+        assert_eq!(lyrics.sections[0].kind, SectionKind::Chorus);
+        assert_eq!(
+            lyrics.sections[0].lines,
+            ["I'm gonna swing", "From the chandelier"]
+        );
+    }
+}
@@ -0,0 +1,183 @@
+use crate::search::Hit;
+use crate::song::Song;
+
+#[cfg(test)]
+mod test {
+    use crate::matching::{artists, normalize, without_featured};
+
+    #[test]
+    fn normalize_test() {
+        assert_eq!(
+            normalize("Cheap Thrills (Remastered 2016)"),
+            "cheap thrills"
+        );
+        assert_eq!(normalize("Cheap Thrills - 2016 Remaster"), "cheap thrills");
+        assert_eq!(
+            normalize("Cheap Thrills [Ft. Sean Paul]"),
+            "cheap thrills feat sean paul"
+        );
+        assert_eq!(normalize("Beyoncé - Halo"), "beyoncé halo");
+        assert_eq!(without_featured("SIA featuring Sean Paul"), "sia");
+        assert_eq!(
+            without_featured("Cheap Thrills (feat. Sean Paul) - Live"),
+            "cheap thrills"
+        );
+    }
+
+    #[test]
+    fn artists_test() {
+        assert_eq!(
+            artists("Sia, Sean Paul & Major Lazer"),
+            [
+                "sia sean paul major lazer",
+                "sia",
+                "sean paul",
+                "major lazer"
+            ]
+        );
+        assert_eq!(
+            artists("Marshmello x Bastille and Khalid"),
+            [
+                "marshmello x bastille and khalid",
+                "marshmello",
+                "bastille",
+                "khalid"
+            ]
+        );
+        assert_eq!(artists("SIA feat. Sean Paul"), ["sia"]);
+    }
+}
+
+/// Words that mark a different release of the same song, like `(Remastered 2011)` or `- Radio Edit`.
+const VERSION_WORDS: [&str; 9] = [
+    "remaster",
+    "remastered",
+    "live",
+    "version",
+    "edit",
+    "mono",
+    "stereo",
+    "deluxe",
+    "bonus",
+];
+
+/// Minimum [`SongMatch::confidence`] of a song returned by [`crate::Genius::find_song`].
+pub const MIN_CONFIDENCE: f64 = 0.7;
+
+/// Song found by [`crate::Genius::find_song`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct SongMatch {
+    pub song: Song,
+    /// How much the artist and the title match the song, from `0.0` to `1.0`.
+    pub confidence: f64,
+}
+
+/// Query to search a song by `artist` and `title`.
+pub(crate) fn query(artist: &str, title: &str) -> String {
+    format!("{} {}", without_featured(artist), without_featured(title))
+}
+
+/// The song of the `hits` that best matches the `artist` and the `title`, `None` if no song reaches [`MIN_CONFIDENCE`].
+pub(crate) fn best_match(artist: &str, title: &str, hits: Vec<Hit>) -> Option<SongMatch> {
+    hits.into_iter()
+        .filter_map(|hit| match hit {
+            Hit::Song(song) => Some(*song),
+            _ => None,
+        })
+        .map(|song| SongMatch {
+            confidence: confidence(artist, title, &song),
+            song,
+        })
+        .filter(|m| m.confidence >= MIN_CONFIDENCE)
+        .max_by(|a, b| a.confidence.total_cmp(&b.confidence))
+}
+
+/// How much the `artist` and the `title` match the `song`, from `0.0` to `1.0`.
+// `f64::midpoint` needs Rust 1.85.
+#[allow(clippy::manual_midpoint)]
+fn confidence(artist: &str, title: &str, song: &Song) -> f64 {
+    let song_artists: Vec<String> = std::iter::once(&song.primary_artist)
+        .chain(song.featured_artists.iter().flatten())
+        .map(|song_artist| normalize(&song_artist.name))
+        .collect();
+    let artist_score = artists(artist)
+        .iter()
+        .flat_map(|artist| {
+            song_artists
+                .iter()
+                .map(move |song_artist| strsim::normalized_levenshtein(artist, song_artist))
+        })
+        .fold(0.0, f64::max);
+    let title_score =
+        strsim::normalized_levenshtein(&without_featured(title), &without_featured(&song.title))
+            .max(strsim::normalized_levenshtein(
+                &normalize(title),
+                &normalize(&song.title_with_featured),
+            ));
+    (artist_score + title_score) / 2.0
+}
+
+/// The whole `artist` followed by each of its artists split on `,`, `&`, `x` and `and`, all without the featured artists.
+fn artists(artist: &str) -> Vec<String> {
+    let whole = without_featured(artist);
+    let mut artists = vec![];
+    for part in artist.split(&[',', '&'][..]) {
+        let part = without_featured(part);
+        let words: Vec<&str> = part.split(' ').collect();
+        artists.extend(
+            words
+                .split(|word| matches!(*word, "x" | "and"))
+                .map(|words| words.join(" "))
+                .filter(|name| !name.is_empty() && *name != whole),
+        );
+    }
+    artists.insert(0, whole);
+    artists
+}
+
+/// Lowercase words of `text` without punctuation and version suffixes, `ft` and `featuring` are replaced by `feat`.
+fn normalize(text: &str) -> String {
+    let text = text.to_lowercase();
+    let text = match text.split_once(" - ") {
+        Some((head, tail)) if is_version(tail) => head,
+        _ => &text,
+    };
+    let mut stripped = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find(&['(', '['][..]) {
+        let Some(len) = rest[start..].find(&[')', ']'][..]) else {
+            break;
+        };
+        stripped.push_str(&rest[..start]);
+        let group = &rest[start + 1..start + len];
+        if !is_version(group) {
+            stripped.push(' ');
+            stripped.push_str(group);
+        }
+        rest = &rest[start + len + 1..];
+    }
+    stripped.push_str(rest);
+    stripped
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(|word| match word {
+            "ft" | "featuring" => "feat",
+            word => word,
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Same as [`normalize`] without the featured artists.
+fn without_featured(text: &str) -> String {
+    normalize(text)
+        .split(' ')
+        .take_while(|word| *word != "feat")
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn is_version(text: &str) -> bool {
+    text.split(|c: char| !c.is_alphanumeric())
+        .any(|word| VERSION_WORDS.contains(&word))
+}
@@ -0,0 +1,87 @@
+use std::sync::Mutex;
+use std::time::Duration;
+use tokio::time::Instant;
+
+#[cfg(test)]
+mod test {
+    use crate::rate_limit::RateLimiter;
+    use std::time::Duration;
+    use tokio::time::Instant;
+
+    #[tokio::test(start_paused = true)]
+    async fn rate_limiter_test() {
+        let limiter = RateLimiter::new(20.0, 2);
+        let start = Instant::now();
+        limiter.acquire().await;
+        limiter.acquire().await;
+        assert_eq!(start.elapsed(), Duration::ZERO);
+        limiter.acquire().await;
+        limiter.acquire().await;
+        let elapsed = start.elapsed();
+        assert!(elapsed >= Duration::from_millis(100) && elapsed < Duration::from_millis(110));
+    }
+}
+
+/// Token bucket that allows `burst` requests at once and then `requests_per_second`.
+#[derive(Debug)]
+pub struct RateLimiter {
+    requests_per_second: f64,
+    burst: f64,
+    bucket: Mutex<Bucket>,
+}
+
+#[derive(Debug)]
+struct Bucket {
+    tokens: f64,
+    updated_at: Instant,
+}
+
+impl RateLimiter {
+    pub fn new(requests_per_second: f64, burst: u32) -> Self {
+        let burst = f64::from(burst.max(1));
+        Self {
+            requests_per_second: requests_per_second.max(f64::EPSILON),
+            burst,
+            bucket: Mutex::new(Bucket {
+                tokens: burst,
+                updated_at: Instant::now(),
+            }),
+        }
+    }
+
+    /// Wait until a request can be sent.
+    pub async fn acquire(&self) {
+        while let Some(wait) = self.try_acquire() {
+            tokio::time::sleep(wait).await;
+        }
+    }
+
+    /// Same as [`RateLimiter::acquire`] blocking the thread.
+    #[cfg(feature = "blocking")]
+    pub fn acquire_blocking(&self) {
+        while let Some(wait) = self.try_acquire() {
+            std::thread::sleep(wait);
+        }
+    }
+
+    /// Take a token from the bucket, if it's empty returns how long until the next token.
+    fn try_acquire(&self) -> Option<Duration> {
+        let mut bucket = self
+            .bucket
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        let now = Instant::now();
+        let elapsed = now.duration_since(bucket.updated_at).as_secs_f64();
+        bucket.tokens = elapsed
+            .mul_add(self.requests_per_second, bucket.tokens)
+            .min(self.burst);
+        bucket.updated_at = now;
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            return None;
+        }
+        Some(Duration::from_secs_f64(
+            (1.0 - bucket.tokens) / self.requests_per_second,
+        ))
+    }
+}
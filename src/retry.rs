@@ -0,0 +1,139 @@
+use reqwest::Method;
+use std::convert::TryFrom;
+use std::time::Duration;
+
+use crate::error::GeniusError;
+
+#[cfg(test)]
+mod test {
+    use crate::retry::RetryPolicy;
+    use reqwest::Method;
+    use std::time::Duration;
+
+    #[test]
+    fn retry_policy_test() {
+        let policy = RetryPolicy::new(3)
+            .base_delay(Duration::from_millis(100))
+            .jitter(Duration::ZERO);
+        assert!(policy.allows(&Method::GET, 1));
+        assert!(policy.allows(&Method::GET, 2));
+        assert!(!policy.allows(&Method::GET, 3));
+        assert!(!policy.allows(&Method::POST, 1));
+        assert!(policy.clone().all_methods(true).allows(&Method::POST, 1));
+        assert_eq!(policy.delay(1, None), Some(Duration::from_millis(100)));
+        assert_eq!(policy.delay(3, None), Some(Duration::from_millis(400)));
+        assert_eq!(
+            policy.delay(3, Some(Duration::from_secs(2))),
+            Some(Duration::from_secs(2))
+        );
+        let policy = policy.max_delay(Duration::from_millis(300));
+        assert_eq!(policy.delay(3, None), Some(Duration::from_millis(300)));
+        assert_eq!(policy.delay(1, Some(Duration::from_secs(90))), None);
+        let policy = policy.jitter(Duration::from_millis(50));
+        let delay = policy.delay(1, None).unwrap();
+        assert!(delay >= Duration::from_millis(100) && delay <= Duration::from_millis(150));
+    }
+}
+
+/// Retry of the requests that failed with a transient error, set with [`crate::builder::GeniusBuilder::retry`].
+///
+/// Requests are retried when the server answers 429 or 5xx, or when the connection fails or times out.
+/// The delay before each retry is the `Retry-After` header if the server sent it, otherwise `base_delay` doubled on every attempt, plus a random `jitter`.
+/// A `Retry-After` longer than `max_delay` isn't waited, the error is returned instead.
+/// By default only `GET` requests are retried, as retrying requests that change data like [`crate::Genius::create_annotation`] could apply them twice.
+/// #### Examples
+/// Basic usage:
+/// ```
+/// use genius_rs::retry::RetryPolicy;
+/// use genius_rs::Genius;
+/// use std::time::Duration;
+///
+/// let genius = Genius::builder("token".to_owned())
+///     .retry(RetryPolicy::new(5).base_delay(Duration::from_secs(1)))
+///     .build();
+/// ```
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    base_delay: Duration,
+    max_delay: Duration,
+    jitter: Duration,
+    all_methods: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::new(3)
+    }
+}
+
+impl RetryPolicy {
+    /// `max_attempts` is the number of times a request is sent counting the first one, so `1` never retries.
+    /// The `base_delay` is 500 milliseconds, the `max_delay` is 30 seconds and the `jitter` is 250 milliseconds.
+    #[must_use]
+    pub const fn new(max_attempts: u32) -> Self {
+        Self {
+            max_attempts,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+            jitter: Duration::from_millis(250),
+            all_methods: false,
+        }
+    }
+
+    /// Delay before the first retry, it doubles on every following retry.
+    #[must_use]
+    pub const fn base_delay(mut self, base_delay: Duration) -> Self {
+        self.base_delay = base_delay;
+        self
+    }
+
+    /// Longest delay before a retry, the doubled `base_delay` stops growing at it and a longer `Retry-After` returns the [`GeniusError::RateLimited`] error instead of waiting.
+    #[must_use]
+    pub const fn max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+
+    /// Maximum random delay added to every retry, so many clients failing at once don't retry at the same time.
+    #[must_use]
+    pub const fn jitter(mut self, jitter: Duration) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Also retry requests that aren't `GET`, like creating or voting annotations.
+    #[must_use]
+    pub const fn all_methods(mut self, all_methods: bool) -> Self {
+        self.all_methods = all_methods;
+        self
+    }
+
+    /// If a request with `method` that failed in the `attempt` (starting from 1) can be sent again.
+    pub(crate) fn allows(&self, method: &Method, attempt: u32) -> bool {
+        attempt < self.max_attempts && (self.all_methods || method == Method::GET)
+    }
+
+    /// Delay before sending again a request that failed in the `attempt` (starting from 1), `None` if `retry_after` is longer than `max_delay`.
+    pub(crate) fn delay(&self, attempt: u32, retry_after: Option<Duration>) -> Option<Duration> {
+        let delay = match retry_after {
+            Some(retry_after) if retry_after > self.max_delay => return None,
+            Some(retry_after) => retry_after,
+            None => self
+                .base_delay
+                .saturating_mul(2_u32.saturating_pow(attempt.saturating_sub(1)))
+                .min(self.max_delay),
+        };
+        let jitter = u64::try_from(self.jitter.as_millis()).unwrap_or(u64::MAX);
+        Some(delay.saturating_add(Duration::from_millis(fastrand::u64(0..=jitter))))
+    }
+
+    /// If the error may not happen again when the request is sent again.
+    pub(crate) fn is_transient(error: &GeniusError) -> bool {
+        match error {
+            GeniusError::RateLimited { .. } | GeniusError::InternalServerError(_) => true,
+            GeniusError::RequestError(e) => e.is_timeout() || e.is_connect(),
+            _ => false,
+        }
+    }
+}
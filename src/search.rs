@@ -1,11 +1,174 @@
-use crate::song::Song;
+use crate::album::Album;
+use crate::song::{Artist, Song};
+use crate::user::User;
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::convert::TryFrom;
 
-#[derive(Deserialize, Debug)]
-pub struct Hit {
-    pub index: String,
+#[cfg(test)]
+mod test {
+    use crate::search::{Hit, SearchSection, SectionType};
+
+    #[test]
+    fn hit_test() {
+        let hits: Vec<Hit> = serde_json::from_str(
+            r#"[
+                {
+                    "highlights": [],
+                    "index": "article",
+                    "type": "article",
+                    "result": {
+                        "api_path": "/articles/1",
+                        "id": 1,
+                        "title": "Sia explains Chandelier",
+                        "url": "https://genius.com/a/sia-explains-chandelier"
+                    }
+                },
+                {
+                    "highlights": [],
+                    "index": "video",
+                    "type": "video",
+                    "result": {
+                        "api_path": "/videos/2",
+                        "id": 2,
+                        "title": "Sia breaks down Chandelier",
+                        "url": "https://genius.com/videos/sia-breaks-down-chandelier"
+                    }
+                }
+            ]"#,
+        )
+        .unwrap();
+        assert!(matches!(&hits[0], Hit::Article(article) if article.id == 1));
+        assert!(matches!(&hits[1], Hit::Video(video) if video.id == 2));
+        assert!(hits[0].song().is_none());
+        let section: SearchSection = serde_json::from_str(
+            r#"{"type": "podcast", "hits": [{"index": "podcast", "type": "podcast", "result": {"id": 3}}]}"#,
+        )
+        .unwrap();
+        assert_eq!(section.section_type, SectionType::Unknown);
+        assert_eq!(section.hits, [Hit::Unknown]);
+        let error = serde_json::from_str::<Hit>(r#"{"type": "article", "result": {"id": 1}}"#);
+        assert!(error.is_err());
+        let section: SearchSection =
+            serde_json::from_str(r#"{"type": "top_hit", "hits": []}"#).unwrap();
+        assert_eq!(section.section_type, SectionType::TopHit);
+    }
+}
+
+/// Result of a search, the variant is the `type` of the hit.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(
+    tag = "type",
+    content = "result",
+    rename_all = "snake_case",
+    try_from = "RawHit"
+)]
+pub enum Hit {
+    Song(Box<Song>),
+    Artist(Artist),
+    Album(Box<Album>),
+    User(User),
+    Article(Article),
+    Video(Video),
+    /// Hit of a type this library doesn't know.
+    Unknown,
+}
+
+#[derive(Deserialize)]
+struct RawHit {
     #[serde(rename = "type")]
-    pub hit_type: String,
-    pub result: Song,
+    hit_type: String,
+    #[serde(default)]
+    result: Value,
+}
+
+impl TryFrom<RawHit> for Hit {
+    type Error = serde_json::Error;
+
+    fn try_from(raw: RawHit) -> Result<Self, Self::Error> {
+        let result = raw.result;
+        Ok(match raw.hit_type.as_str() {
+            "song" => Self::Song(serde_json::from_value(result)?),
+            "artist" => Self::Artist(serde_json::from_value(result)?),
+            "album" => Self::Album(serde_json::from_value(result)?),
+            "user" => Self::User(serde_json::from_value(result)?),
+            "article" => Self::Article(serde_json::from_value(result)?),
+            "video" => Self::Video(serde_json::from_value(result)?),
+            _ => Self::Unknown,
+        })
+    }
+}
+
+impl Hit {
+    /// The song of the hit, `None` if it's not a song.
+    #[must_use]
+    pub fn song(&self) -> Option<&Song> {
+        match self {
+            Self::Song(song) => Some(song),
+            _ => None,
+        }
+    }
+}
+
+/// Hits of a type in [`crate::Genius::search_multi`].
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct SearchSection {
+    #[serde(rename = "type")]
+    pub section_type: SectionType,
+    pub hits: Vec<Hit>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SectionType {
+    /// The best hit of all types.
+    TopHit,
+    Song,
+    /// Songs where the lyrics match the query, the hits are [`Hit::Song`].
+    Lyric,
+    Artist,
+    Album,
+    Video,
+    Article,
+    User,
+    /// Section of a type this library doesn't know.
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct Article {
+    /// Path of the API.
+    pub api_path: String,
+    /// Authors of the article.
+    pub author_list_for_display: Option<String>,
+    /// Subtitle of the article.
+    pub dek: Option<String>,
+    /// Id of the article.
+    pub id: u32,
+    /// Image shown in the previews of the article.
+    pub preview_image: Option<String>,
+    /// Published timestamp.
+    pub published_at: Option<u64>,
+    /// Title of the article.
+    pub title: String,
+    /// Url of the article page.
+    pub url: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct Video {
+    /// Path of the API.
+    pub api_path: String,
+    /// Subtitle of the video.
+    pub dek: Option<String>,
+    /// Id of the video.
+    pub id: u32,
+    /// Image shown before the video plays.
+    pub poster_url: Option<String>,
+    /// Title of the video.
+    pub title: String,
+    /// Url of the video page.
+    pub url: String,
 }
@@ -1,11 +1,74 @@
-use serde::Deserialize;
+use reqwest::Url;
+use serde::{Deserialize, Serialize};
 
 use crate::album::Album;
 use crate::annotation::Referent;
 use crate::user::{User, UserMetadata};
 use crate::{Body, Date};
 
-#[derive(Deserialize, Debug)]
+#[cfg(test)]
+mod test {
+    use crate::song::{Song, SongPath};
+
+    #[test]
+    fn song_path_test() {
+        assert_eq!(
+            SongPath::parse("https://genius.com/Sia-chandelier-lyrics"),
+            Some(SongPath::Page("/Sia-chandelier-lyrics".to_owned()))
+        );
+        assert_eq!(
+            SongPath::parse("/Sia-chandelier-lyrics"),
+            Some(SongPath::Page("/Sia-chandelier-lyrics".to_owned()))
+        );
+        assert_eq!(
+            SongPath::parse("/songs/378195"),
+            Some(SongPath::Id(378_195))
+        );
+        assert_eq!(
+            SongPath::parse("https://api.genius.com/songs/378195?text_format=plain"),
+            Some(SongPath::Id(378_195))
+        );
+        assert_eq!(
+            SongPath::parse("https://example.com/Sia-chandelier-lyrics"),
+            None
+        );
+        assert_eq!(SongPath::parse("/albums/27501"), None);
+        assert_eq!(
+            SongPath::page_song_id(
+                r#"<meta property="al:ios:url" content="genius://songs/378195" />"#
+            ),
+            Some(378_195)
+        );
+        assert_eq!(SongPath::page_song_id("<html></html>"), None);
+    }
+
+    #[test]
+    fn song_round_trip_test() {
+        let song: Song = serde_json::from_str(
+            r#"{
+                "annotation_count": 1, "api_path": "/songs/378195", "full_title": "Chandelier by Sia",
+                "header_image_thumbnail_url": "", "header_image_url": "", "id": 378195, "lyrics_owner_id": 1,
+                "lyrics_state": "complete", "path": "/Sia-chandelier-lyrics", "song_art_image_thumbnail_url": "",
+                "song_art_image_url": "", "title": "Chandelier", "title_with_featured": "Chandelier",
+                "url": "https://genius.com/Sia-chandelier-lyrics",
+                "stats": {"unreviewed_annotations": 0, "hot": false, "pageviews": 42},
+                "description": {"plain": "About", "dom": {"tag": "root", "children": [{"tag": "p", "children": ["About"]}]}},
+                "release_date_components": {"year": 2014, "month": 3, "day": 17},
+                "media": [{"provider": "youtube", "start": 0, "type": "video", "url": "https://youtu.be/2vjPBrBU-TM"}],
+                "primary_artist": {
+                    "api_path": "/artists/16775", "header_image_url": "", "id": 16775, "image_url": "",
+                    "index_character": "s", "is_meme_verified": false, "is_verified": true, "name": "Sia",
+                    "url": "https://genius.com/artists/Sia"
+                }
+            }"#,
+        )
+        .unwrap();
+        let json = serde_json::to_string(&song).unwrap();
+        assert_eq!(serde_json::from_str::<Song>(&json).unwrap(), song);
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct Song {
     /// Number of annotations on this song.
     pub annotation_count: u32,
@@ -178,20 +241,20 @@ pub struct Song {
     pub writer_artists: Option<Vec<Artist>>,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 pub struct TrackingPaths {
     pub aggregate: String,
     pub concurrent: String,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 pub struct SongContributor {
     pub contributions: Vec<String>,
     pub artist: Artist,
     pub user: Option<User>,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct SongRelationship {
     /// The type of relationship can be `samples`, `sampled_in`, `interpolates`, `interpolated_by`, `cover_of`, `covered_by`, `remix_of`, `remixed_by`, `live_version_of` and `performed_live_as`.
     pub relationship_type: String,
@@ -199,13 +262,13 @@ pub struct SongRelationship {
     pub songs: Vec<Option<Song>>,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 pub struct SongPerformance {
     pub label: String,
     pub artists: Vec<Artist>,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 pub struct SongMedia {
     /// Spotify path of the song with `:` instead `/`, weird.
     pub native_uri: Option<String>,
@@ -222,7 +285,7 @@ pub struct SongMedia {
     pub url: String,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 pub struct SongStatus {
     /// Number of annotations accepted on this song.
     pub accepted_annotations: Option<u32>,
@@ -242,7 +305,7 @@ pub struct SongStatus {
     pub pageviews: Option<u32>,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 pub struct Artist {
     /// Path of the API.
     pub api_path: String,
@@ -268,3 +331,46 @@ pub struct Artist {
     /// How much iq this artist has.
     pub iq: Option<u32>,
 }
+
+/// Location of a song parsed from a genius.com url, a [`Song::path`] or a [`Song::api_path`].
+#[derive(Debug, PartialEq, Eq)]
+pub enum SongPath {
+    /// The id of the song, from an `api_path` like `/songs/378195`.
+    Id(u32),
+    /// The path of the song page, like `/Sia-chandelier-lyrics`.
+    Page(String),
+}
+
+impl SongPath {
+    /// Parse an url like <https://genius.com/Sia-chandelier-lyrics> or a path like `/Sia-chandelier-lyrics` or `/songs/378195`.
+    /// Returns `None` if it isn't a genius song.
+    #[must_use]
+    pub fn parse(url: &str) -> Option<Self> {
+        let url = url.trim();
+        let path = if url.starts_with('/') {
+            url.split(['?', '#']).next()?.to_owned()
+        } else {
+            let url = Url::parse(url).ok()?;
+            let host = url.host_str()?;
+            if host != "genius.com" && !host.ends_with(".genius.com") {
+                return None;
+            }
+            url.path().to_owned()
+        };
+        let segments: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+        match segments.as_slice() {
+            ["songs", id] => id.parse().ok().map(Self::Id),
+            [page] if page.ends_with("-lyrics") => Some(Self::Page(format!("/{page}"))),
+            _ => None,
+        }
+    }
+
+    /// Id of the song of a song page, from its app link like `genius://songs/378195`.
+    pub(crate) fn page_song_id(html: &str) -> Option<u32> {
+        let (_, rest) = html.split_once("genius://songs/")?;
+        let end = rest
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len());
+        rest[..end].parse().ok()
+    }
+}
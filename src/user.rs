@@ -1,7 +1,9 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap as Map;
 
-#[derive(Deserialize, Debug)]
+use crate::Body;
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 pub struct User {
     /// User api path
     pub api_path: String,
@@ -27,7 +29,7 @@ pub struct User {
     pub current_user_metadata: UserMetadata,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 pub struct AvatarImage {
     /// Image url.
     pub url: String,
@@ -35,7 +37,7 @@ pub struct AvatarImage {
     pub bounding_box: Map<String, u32>,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 pub struct UserMetadata {
     /// Permissions you have.
     pub permissions: Vec<String>,
@@ -44,9 +46,57 @@ pub struct UserMetadata {
     pub interactions: Option<Interactions>,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 pub struct Interactions {
     pub cosign: Option<bool>,
     pub pyong: Option<bool>,
-    pub vote: Option<u32>,
+    /// Vote of the user, `up` or `down`.
+    pub vote: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Account {
+    /// Public information of the user.
+    #[serde(flatten)]
+    pub user: User,
+    /// About me of the user.
+    pub about_me: Option<Body>,
+    /// User email.
+    pub email: Option<String>,
+    /// Number of users that this user follows.
+    pub followed_users_count: Option<u32>,
+    /// Number of users that follow this user.
+    pub followers_count: Option<u32>,
+    /// User iq formatted to display like `1.2K`.
+    pub iq_for_display: Option<String>,
+    /// All the roles of the user.
+    pub roles_for_display: Option<Vec<String>>,
+    /// Number of unread groups inbox.
+    pub unread_groups_inbox_count: Option<u32>,
+    /// Number of unread main activity inbox.
+    pub unread_main_activity_inbox_count: Option<u32>,
+    /// Number of unread messages.
+    pub unread_messages_count: Option<u32>,
+    /// Number of unread newsfeed inbox.
+    pub unread_newsfeed_inbox_count: Option<u32>,
+    /// Contributions of the user.
+    pub stats: Option<UserStats>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct UserStats {
+    /// Number of annotations.
+    pub annotations_count: Option<u32>,
+    /// Number of answers.
+    pub answers_count: Option<u32>,
+    /// Number of comments.
+    pub comments_count: Option<u32>,
+    /// Number of forum posts.
+    pub forum_posts_count: Option<u32>,
+    /// Number of pyongs.
+    pub pyongs_count: Option<u32>,
+    /// Number of questions.
+    pub questions_count: Option<u32>,
+    /// Number of transcriptions.
+    pub transcriptions_count: Option<u32>,
 }
@@ -0,0 +1,21 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct WebPage {
+    /// Number of annotations on this web page.
+    pub annotation_count: u32,
+    /// Path of the API.
+    pub api_path: String,
+    /// Domain of the web page.
+    pub domain: String,
+    /// Id of the web page, can be used in [`crate::Genius::get_referents`].
+    pub id: u32,
+    /// Url of the web page without protocol, query and fragment.
+    pub normalized_url: String,
+    /// Share url in genius.com.
+    pub share_url: String,
+    /// Title of the web page.
+    pub title: String,
+    /// Url of the web page.
+    pub url: String,
+}